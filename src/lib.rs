@@ -27,6 +27,9 @@
 extern crate type_operators;
 
 pub mod types;
+// The operator aliases which `type_operators!` generates repeat their parameters' kind bounds,
+// which rustc warns are not enforced on type aliases.
+#[allow(type_alias_bounds)]
 pub mod strong;
 
 pub use strong::*;
@@ -0,0 +1,170 @@
+//! Compile-time assertions. Each of these is a marker trait which is implemented exactly when
+//! its assertion holds, so it can be used in a `where` clause to check an invariant, or with the
+//! `static_assert!` macro at item level. When an assertion fails, the compiler reports it with
+//! a short message naming the types involved, rather than a long chain of unsatisfied operator
+//! bounds. Unfortunately the compiler can only print types, not their values, so a `Nat` such as
+//! `U5` will be shown in its ternary form, `Two<One<Term>>`.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate type_level_logic;
+//!
+//! use type_level_logic::strong::ternary::{Add, U2, U3, U5};
+//! use type_level_logic::strong::ternary::number_theory::IsPrime;
+//!
+//! static_assert!(IsPrime<U5>);
+//! static_assert!(eq: Add<U2, U3>, U5);
+//! static_assert!(lt: U3, U5);
+//! # fn main() {}
+//! ```
+//!
+//! A failing assertion is a compile error:
+//!
+//! ```compile_fail
+//! # #[macro_use]
+//! # extern crate type_level_logic;
+//! # use type_level_logic::strong::ternary::{U3, U5};
+//! static_assert!(lt: U5, U3);
+//! # fn main() {}
+//! ```
+
+use strong::boolean::True;
+use strong::ordering::Less;
+use strong::ternary::{self, Nat, NatCompare, NatIsDefined};
+use strong::balanced::{self, Int, IntCompare, IntIsDefined};
+
+/// Asserts that a `Bool` is `True`. Used as `B: AssertTrue`.
+#[diagnostic::on_unimplemented(
+    message = "assertion failed: `{Self}` is not `True`",
+    label = "this must evaluate to `True`"
+)]
+pub trait AssertTrue {}
+
+impl AssertTrue for True {}
+
+/// Asserts that two types are the same. Used as `X: AssertEq<Y>`. Since every type-level number
+/// has only one canonical representation, and the operators in this crate produce canonical
+/// results, this is equality for `Nat`s and `Int`s.
+#[diagnostic::on_unimplemented(
+    message = "assertion failed: `{Self}` is not equal to `{Y}`",
+    label = "these must be equal"
+)]
+pub trait AssertEq<Y> {}
+
+impl<X> AssertEq<X> for X {}
+
+/// Asserts that one `Nat` or `Int` is less than another. Used as `X: AssertLt<Y>`.
+#[diagnostic::on_unimplemented(
+    message = "assertion failed: `{Self}` is not less than `{Y}`",
+    label = "this must be less than `{Y}`"
+)]
+pub trait AssertLt<Y> {}
+
+/// Asserts that a `Nat` or `Int` is defined, containing no errors such as `Undefined`. Used as
+/// `N: AssertDefined`.
+#[diagnostic::on_unimplemented(
+    message = "assertion failed: `{Self}` is not defined",
+    label = "this must be defined"
+)]
+pub trait AssertDefined {}
+
+/// The comparison underlying `AssertLt`, which must come out `Less`.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "assertion failed: the left-hand side is not less than the right-hand side",
+    label = "the comparison came out `{Self}`, not `Less`"
+)]
+pub trait AssertLtHolds {}
+
+impl AssertLtHolds for Less {}
+
+/// The test underlying `AssertDefined`, which must come out `True`.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "assertion failed: the value contains an error, and is not defined",
+    label = "this is not defined"
+)]
+pub trait AssertDefinedHolds {}
+
+impl AssertDefinedHolds for True {}
+
+// `AssertLt` and `AssertDefined` have to be implemented constructor by constructor: blanket
+// implementations for `Nat`s and for `Int`s would overlap, as far as the compiler knows. The
+// error values have no implementations at all.
+macro_rules! impl_asserts {
+    ($module:ident: $kind:ident, $compare:ident, $is_defined:ident;
+     $($ctor:ident $(<$n:ident>)*),*) => {
+        $(
+            impl<$($n: $kind,)* Y: $kind> AssertLt<Y> for $module::$ctor$(<$n>)*
+                where $module::$ctor$(<$n>)*: $compare<Y>,
+                      <$module::$ctor$(<$n>)* as $compare<Y>>::Output: AssertLtHolds {}
+
+            impl<$($n: $kind)*> AssertDefined for $module::$ctor$(<$n>)*
+                where $module::$ctor$(<$n>)*: $is_defined,
+                      <$module::$ctor$(<$n>)* as $is_defined>::Output: AssertDefinedHolds {}
+        )*
+    };
+}
+
+impl_asserts!(ternary: Nat, NatCompare, NatIsDefined; Term, Zero<N>, One<N>, Two<N>);
+impl_asserts!(balanced: Int, IntCompare, IntIsDefined; Term, Zero<N>, Plus<N>, Minus<N>);
+
+
+/// Check an assertion at compile time, at item level. `static_assert!(B)` asserts that the
+/// `Bool` `B` is `True`; `static_assert!(eq: X, Y)` that `X` and `Y` are equal;
+/// `static_assert!(lt: X, Y)` that `X` is less than `Y`; and `static_assert!(defined: N)` that
+/// `N` is defined.
+#[macro_export]
+macro_rules! static_assert {
+    (eq: $x:ty, $y:ty) => {
+        static_assert!(@check $x: $crate::strong::assert::AssertEq<$y>);
+    };
+    (lt: $x:ty, $y:ty) => {
+        static_assert!(@check $x: $crate::strong::assert::AssertLt<$y>);
+    };
+    (defined: $n:ty) => {
+        static_assert!(@check $n: $crate::strong::assert::AssertDefined);
+    };
+    (@check $t:ty: $bound:path) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert<T: $bound>() {}
+            #[allow(dead_code)]
+            fn check() {
+                assert::<$t>();
+            }
+        };
+    };
+    ($b:ty) => {
+        static_assert!(@check $b: $crate::strong::assert::AssertTrue);
+    };
+}
+
+
+#[cfg(test)]
+mod tests {
+    use strong::balanced::{self, SN5, SN2, SP3};
+    use strong::ternary::{Add, Div, Pred, Sub, U0, U2, U3, U5, U7, U9, U26};
+    use strong::ternary::number_theory::IsPrime;
+    use super::*;
+
+    static_assert!(IsPrime<U7>);
+    static_assert!(eq: Add<U2, U3>, U5);
+    static_assert!(eq: balanced::Sub<SN2, SP3>, SN5);
+    static_assert!(lt: U3, U5);
+    static_assert!(lt: U0, U9);
+    static_assert!(lt: SN5, SN2);
+    static_assert!(defined: Sub<U9, U5>);
+    static_assert!(defined: Pred<U2>);
+    static_assert!(defined: balanced::Div<SP3, SN2>);
+
+    #[test]
+    fn assert_bounds() {
+        fn small<X: Nat + AssertDefined + AssertLt<U9>>() -> usize {
+            X::reify()
+        }
+
+        assert_eq!(small::<U7>(), 7);
+        assert_eq!(small::<Div<U26, U3>>(), 8);
+    }
+}
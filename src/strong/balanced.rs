@@ -3,64 +3,121 @@
 
 pub use types::balanced::*;
 
+use strong::boolean::{Bool, BoolNot, False, True};
+use strong::ordering::{Ordering, OrdMatch, Match, Less, Equal, Greater};
+use strong::ternary::{Nat, NatError, NatPred, NatSucc, Term as NatTerm, Zero as NatZero,
+                      One as NatOne, Two as NatTwo, Undefined as NatUndefined,
+                      DivByZero as NatDivByZero, Underflow as NatUnderflow};
+
 type_operators! {
     [A, B, C, D, E, F, G, H]
 
-    /// Project the first value in an `IntPair`.
+    /// Project the first value in an `IntPair`. Used as `Int2First<P>` or `<P as Int2P1>::Output`;
+    /// for example, `Int2First<DivRem<X, Y>>` is the quotient of `X` by `Y`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Int2First<{Self}>`",
+        label = "`Int2First` is not implemented for these arguments",
+        note = "`Int2First` takes an `IntPair`",
+        note = "in generic code, add `{Self}: Int2P1` to the `where` clause"
+    )]
     (Int2First) Int2P1(IntPair): Int {
         forall (A: Int, B: Int) {
             [(Int2 A B)] => A
         }
     }
 
-    /// Project the second value in an `IntPair`.
+    /// Project the second value in an `IntPair`. Used as `Int2Second<P>` or
+    /// `<P as Int2P2>::Output`; for example, `Int2Second<DivRem<X, Y>>` is the remainder of `X`
+    /// divided by `Y`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Int2Second<{Self}>`",
+        label = "`Int2Second` is not implemented for these arguments",
+        note = "`Int2Second` takes an `IntPair`",
+        note = "in generic code, add `{Self}: Int2P2` to the `where` clause"
+    )]
     (Int2Second) Int2P2(IntPair): Int {
         forall (A: Int, B: Int) {
             [(Int2 A B)] => B
         }
     }
 
+    /// Swap the two values in an `IntPair`. Used as `Int2Swap<P>` or `<P as Int2Sw>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Int2Swap<{Self}>`",
+        label = "`Int2Swap` is not implemented for these arguments",
+        note = "`Int2Swap` takes an `IntPair`",
+        note = "in generic code, add `{Self}: Int2Sw` to the `where` clause"
+    )]
+    (Int2Swap) Int2Sw(IntPair): IntPair {
+        forall (A: Int, B: Int) {
+            [(Int2 A B)] => (Int2 B A)
+        }
+    }
+
     /// The `Succ` operator adds one to an integer.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Succ<{Self}>`",
+        label = "`Succ` is not implemented for these arguments",
+        note = "`Succ` takes an `Int`",
+        note = "in generic code, add `{Self}: IntSucc` to the `where` clause"
+    )]
     (Succ) IntSucc(Int): Int {
-        [Undefined] => Undefined
         [Term] => Plus
         forall (N: Int) {
             [(Zero N)] => (Plus N)
             [(Plus N)] => (Minus (# N))
             [(Minus N)] => (@Unique (Zero N))
         }
+        forall (U: IntError) {
+            [U] => U
+        }
     }
 
     /// The `Pred` operator subtracts one to an integer.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Pred<{Self}>`",
+        label = "`Pred` is not implemented for these arguments",
+        note = "`Pred` takes an `Int`",
+        note = "in generic code, add `{Self}: IntPred` to the `where` clause"
+    )]
     (Pred) IntPred(Int): Int {
-        [Undefined] => Undefined
         [Term] => Minus
         forall (N: Int) {
             [(Zero N)] => (Minus N)
             [(Plus N)] => (@Unique (Zero N))
             [(Minus N)] => (Plus (# N))
         }
+        forall (U: IntError) {
+            [U] => U
+        }
     }
 
     /// The `Triple` operator triples an integer, and avoids redundant zeroes (and thus preserves
     /// unique representations.) It is equivalent to `Zero<N>` unless `N` is `Term`, in which case
     /// `Triple<Term> = Term`. Its collapsing acts at only one level, which should be sufficient
     /// unless multiple levels of redundant zeroes are introduced by user error.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Triple<{Self}>`",
+        label = "`Triple` is not implemented for these arguments",
+        note = "`Triple` takes an `Int`",
+        note = "in generic code, add `{Self}: IntTriple` to the `where` clause"
+    )]
     (Triple) IntTriple(Int): Int {
-        [Undefined] => Undefined
         [Term] => Term
         forall (N: Int) {
             [(Zero N)] => (Zero (Zero N))
             [(Plus N)] => (Zero (Plus N))
             [(Minus N)] => (Zero (Minus N))
         }
+        forall (U: IntError) {
+            [U] => U
+        }
     }
 
     /// The `Unique` operator collapses one level of redundant zeroes in a balanced ternary
     /// representation. Using `Unique` every time we might get a redundant zero *should* get rid of
     /// any problems with non-unique representations of zero.
     (IntUnique) Unique(Int): Int {
-        [Undefined] => Undefined
         [Term] => Term
         [(Zero Term)] => Term
         forall (N: Int) {
@@ -70,6 +127,300 @@ type_operators! {
             [(Plus N)] => (Plus N)
             [(Minus N)] => (Minus N)
         }
+        forall (U: IntError) {
+            [U] => U
+        }
+    }
+
+    /// Drop the least significant trit of an integer, dividing it by three and rounding to the
+    /// nearest integer. Used as `Third<X>` or `<X as IntThird>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Third<{Self}>`",
+        label = "`Third` is not implemented for these arguments",
+        note = "`Third` takes an `Int`",
+        note = "in generic code, add `{Self}: IntThird` to the `where` clause"
+    )]
+    (Third) IntThird(Int): Int {
+        [Term] => Term
+        forall (N: Int) {
+            [(Zero N)] => N
+            [(Plus N)] => N
+            [(Minus N)] => N
+        }
+        forall (U: IntError) {
+            [U] => U
+        }
+    }
+
+    /// The `Int` error standing for a `Nat` error, for operators which take a `Nat` argument but
+    /// return an `Int`. Used as `IntFromNatError<U>` or `<U as NatErrorToInt>::Output`. A
+    /// `DivByZero` stays a `DivByZero`; `Int`s have no underflow, so an `Underflow<L, R>` becomes
+    /// `Undefined`, as does `Undefined` itself.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IntFromNatError<{Self}>`",
+        label = "`IntFromNatError` is not implemented for these arguments",
+        note = "`IntFromNatError` takes a `Nat` error value",
+        note = "in generic code, add `{Self}: NatErrorToInt` to the `where` clause"
+    )]
+    (IntFromNatError) NatErrorToInt(Nat): Int {
+        [NatUndefined] => Undefined
+        [NatDivByZero] => DivByZero
+        forall (L: Nat, R: Nat) {
+            [(NatUnderflow L R)] => Undefined
+        }
+    }
+
+    /// The `Nat` error standing for an `Int` error, for operators which take an `Int` argument but
+    /// return a `Nat`. Used as `NatFromIntError<U>` or `<U as IntErrorToNat>::Output`. Each `Int`
+    /// error becomes the `Nat` error of the same name.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `NatFromIntError<{Self}>`",
+        label = "`NatFromIntError` is not implemented for these arguments",
+        note = "`NatFromIntError` takes an `Int` error value",
+        note = "in generic code, add `{Self}: IntErrorToNat` to the `where` clause"
+    )]
+    (NatFromIntError) IntErrorToNat(Int): Nat {
+        [Undefined] => NatUndefined
+        [DivByZero] => NatDivByZero
+    }
+
+    /// Shift an integer left by `K` trits, multiplying it by `3^K`. Here `K` is a `Nat`. Used as
+    /// `ShiftLeft<N, K>` or `<N as IntShiftLeft<K>>::Output`. Shifting zero yields zero, so the
+    /// result is canonical whenever `N` is. An error in `K` becomes an `Int` error as described
+    /// for `IntFromNatError`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `ShiftLeft<{Self}, {B}>`",
+        label = "`ShiftLeft` is not implemented for these arguments",
+        note = "`ShiftLeft` takes an `Int` and a `Nat`",
+        note = "in generic code, add `{Self}: IntShiftLeft<{B}>` to the `where` clause"
+    )]
+    (ShiftLeft) IntShiftLeft(Int, Nat): Int {
+        forall (N: Int) {
+            [N, NatTerm] => N
+        }
+        forall (N: Int, K: Nat) {
+            [N, (NatZero K)] => (@IntTriple (# N (@NatPred (NatZero K))))
+            [N, (NatOne K)] => (@IntTriple (# N (@NatPred (NatOne K))))
+            [N, (NatTwo K)] => (@IntTriple (# N (@NatPred (NatTwo K))))
+        }
+        forall (N: Int, U: NatError) {
+            [N, U] => (@NatErrorToInt U)
+        }
+    }
+
+    /// Shift an integer right by `K` trits, where `K` is a `Nat`. Used as `ShiftRight<N, K>` or
+    /// `<N as IntShiftRight<K>>::Output`. In balanced ternary, the trits shifted out always make
+    /// up less than half of `3^K` in absolute value, so this divides by `3^K` and rounds to the
+    /// nearest integer, with no ties possible. An error in `K` becomes an `Int` error as
+    /// described for `IntFromNatError`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `ShiftRight<{Self}, {B}>`",
+        label = "`ShiftRight` is not implemented for these arguments",
+        note = "`ShiftRight` takes an `Int` and a `Nat`",
+        note = "in generic code, add `{Self}: IntShiftRight<{B}>` to the `where` clause"
+    )]
+    (ShiftRight) IntShiftRight(Int, Nat): Int {
+        forall (N: Int) {
+            [N, NatTerm] => N
+        }
+        forall (N: Int, K: Nat) {
+            [N, (NatZero K)] => (# (@IntThird N) (@NatPred (NatZero K)))
+            [N, (NatOne K)] => (# (@IntThird N) (@NatPred (NatOne K)))
+            [N, (NatTwo K)] => (# (@IntThird N) (@NatPred (NatTwo K)))
+        }
+        forall (N: Int, U: NatError) {
+            [N, U] => (@NatErrorToInt U)
+        }
+    }
+
+    /// The lowest `K` trits of an integer, where `K` is a `Nat`. Used as `LowTrits<N, K>` or
+    /// `<N as IntLowTrits<K>>::Output`. This is the remainder of `N` modulo `3^K` lying strictly
+    /// between `-3^K / 2` and `3^K / 2`, and so may be negative. Leading zero trits are removed, so
+    /// the result is canonical. An error in `K` becomes an `Int` error as described for
+    /// `IntFromNatError`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `LowTrits<{Self}, {B}>`",
+        label = "`LowTrits` is not implemented for these arguments",
+        note = "`LowTrits` takes an `Int` and a `Nat`",
+        note = "in generic code, add `{Self}: IntLowTrits<{B}>` to the `where` clause"
+    )]
+    (LowTrits) IntLowTrits(Int, Nat): Int {
+        [Term, NatTerm] => Term
+        forall (K: Nat) {
+            [Term, (NatZero K)] => Term
+            [Term, (NatOne K)] => Term
+            [Term, (NatTwo K)] => Term
+        }
+        forall (N: Int) {
+            [(Zero N), NatTerm] => Term
+            [(Plus N), NatTerm] => Term
+            [(Minus N), NatTerm] => Term
+        }
+        forall (N: Int, K: Nat) {
+            [(Zero N), (NatZero K)] => (@IntTriple (# N (@NatPred (NatZero K))))
+            [(Zero N), (NatOne K)] => (@IntTriple (# N (@NatPred (NatOne K))))
+            [(Zero N), (NatTwo K)] => (@IntTriple (# N (@NatPred (NatTwo K))))
+            [(Plus N), (NatZero K)] => (Plus (# N (@NatPred (NatZero K))))
+            [(Plus N), (NatOne K)] => (Plus (# N (@NatPred (NatOne K))))
+            [(Plus N), (NatTwo K)] => (Plus (# N (@NatPred (NatTwo K))))
+            [(Minus N), (NatZero K)] => (Minus (# N (@NatPred (NatZero K))))
+            [(Minus N), (NatOne K)] => (Minus (# N (@NatPred (NatOne K))))
+            [(Minus N), (NatTwo K)] => (Minus (# N (@NatPred (NatTwo K))))
+        }
+        forall (Y: Nat, U: IntError) {
+            [U, Y] => U
+        }
+        forall (U: NatError) {
+            [Term, U] => (@NatErrorToInt U)
+        }
+        forall (N: Int, U: NatError) {
+            [(Zero N), U] => (@NatErrorToInt U)
+            [(Plus N), U] => (@NatErrorToInt U)
+            [(Minus N), U] => (@NatErrorToInt U)
+        }
+    }
+
+    /// An integer with its lowest `K` trits cleared, where `K` is a `Nat`. This is `N` rounded to
+    /// the nearest multiple of `3^K`. Used as `HighTrits<N, K>` or
+    /// `<N as IntHighTrits<K>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `HighTrits<{Self}, {B}>`",
+        label = "`HighTrits` is not implemented for these arguments",
+        note = "`HighTrits` takes an `Int` and a `Nat`",
+        note = "in generic code, add `{Self}: IntHighTrits<{B}>` to the `where` clause"
+    )]
+    (HighTrits) IntHighTrits(Int, Nat): Int {
+        forall (N: Int, K: Nat) {
+            [N, K] => (@IntShiftLeft (@IntShiftRight N K) K)
+        }
+    }
+
+    /// The number of trits in an integer, as a `Nat`. Used as `TritLen<N>` or
+    /// `<N as IntTritLen>::Output`. Zero has no trits, so `TritLen<S0> = U0`. An `Int` error
+    /// becomes the `Nat` error of the same name.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `TritLen<{Self}>`",
+        label = "`TritLen` is not implemented for these arguments",
+        note = "`TritLen` takes an `Int`",
+        note = "in generic code, add `{Self}: IntTritLen` to the `where` clause"
+    )]
+    (TritLen) IntTritLen(Int): Nat {
+        [Term] => NatTerm
+        forall (N: Int) {
+            [(Zero N)] => (@NatSucc (# N))
+            [(Plus N)] => (@NatSucc (# N))
+            [(Minus N)] => (@NatSucc (# N))
+        }
+        forall (U: IntError) {
+            [U] => (@IntErrorToNat U)
+        }
+    }
+
+    /// The number of nonzero trits in an integer, as a `Nat`. Used as `NonZeroTrits<N>` or
+    /// `<N as IntNonZeroTrits>::Output`. An `Int` error becomes the `Nat` error of the same name.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `NonZeroTrits<{Self}>`",
+        label = "`NonZeroTrits` is not implemented for these arguments",
+        note = "`NonZeroTrits` takes an `Int`",
+        note = "in generic code, add `{Self}: IntNonZeroTrits` to the `where` clause"
+    )]
+    (NonZeroTrits) IntNonZeroTrits(Int): Nat {
+        [Term] => NatTerm
+        forall (N: Int) {
+            [(Zero N)] => (# N)
+            [(Plus N)] => (@NatSucc (# N))
+            [(Minus N)] => (@NatSucc (# N))
+        }
+        forall (U: IntError) {
+            [U] => (@IntErrorToNat U)
+        }
+    }
+
+    /// Bring an integer into canonical form, removing any number of redundant leading zeroes.
+    /// Used as `Normalize<X>` or `<X as IntNormalize>::Output`. Unlike `Unique`, this walks the
+    /// whole integer, so `Normalize<Zero<Zero<Term>>> = Term`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Normalize<{Self}>`",
+        label = "`Normalize` is not implemented for these arguments",
+        note = "`Normalize` takes an `Int`",
+        note = "in generic code, add `{Self}: IntNormalize` to the `where` clause"
+    )]
+    (Normalize) IntNormalize(Int): Int {
+        [Term] => Term
+        forall (N: Int) {
+            [(Zero N)] => (@IntTriple (# N))
+            [(Plus N)] => (Plus (# N))
+            [(Minus N)] => (Minus (# N))
+        }
+        forall (U: IntError) {
+            [U] => U
+        }
+    }
+
+    /// Whether an integer is in canonical form, with no redundant leading zeroes. Used as
+    /// `IsCanonical<X>` or `<X as IntIsCanonical>::Output`. `Undefined`, and anything containing
+    /// it, is not canonical.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IsCanonical<{Self}>`",
+        label = "`IsCanonical` is not implemented for these arguments",
+        note = "`IsCanonical` takes an `Int`",
+        note = "in generic code, add `{Self}: IntIsCanonical` to the `where` clause"
+    )]
+    (IsCanonical) IntIsCanonical(Int): Bool {
+        [Term] => True
+        [(Zero Term)] => False
+        forall (N: Int) {
+            [(Zero (Zero N))] => (# (Zero N))
+            [(Zero (Plus N))] => (# (Plus N))
+            [(Zero (Minus N))] => (# (Minus N))
+            [(Plus N)] => (# N)
+            [(Minus N)] => (# N)
+        }
+        forall (U: IntError) {
+            [U] => False
+            [(Zero U)] => False
+        }
+    }
+
+    /// Whether an `Int` is defined; that is, whether it contains no errors such as `Undefined`.
+    /// Used as `IsDefined<X>` or `<X as IntIsDefined>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IsDefined<{Self}>`",
+        label = "`IsDefined` is not implemented for these arguments",
+        note = "`IsDefined` takes an `Int`",
+        note = "in generic code, add `{Self}: IntIsDefined` to the `where` clause"
+    )]
+    (IsDefined) IntIsDefined(Int): Bool {
+        [Term] => True
+        forall (N: Int) {
+            [(Zero N)] => (# N)
+            [(Plus N)] => (# N)
+            [(Minus N)] => (# N)
+        }
+        forall (U: IntError) {
+            [U] => False
+        }
+    }
+
+    /// Replace an undefined `Int` with a default. Used as `UnwrapOr<X, D>` or
+    /// `<X as IntUnwrapOr<D>>::Output`. The result is `X` if `X` is defined, and `D` otherwise.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `UnwrapOr<{Self}, {B}>`",
+        label = "`UnwrapOr` is not implemented for these arguments",
+        note = "`UnwrapOr` takes two `Int`s",
+        note = "in generic code, add `{Self}: IntUnwrapOr<{B}>` to the `where` clause"
+    )]
+    (UnwrapOr) IntUnwrapOr(Int, Int): Int {
+        forall (X: Int, D: Int) {
+            [X, D] => (@IntUnwrapOrInternal (@IntIsDefined X) X D)
+        }
+    }
+
+    (UnwrapOrInternal) IntUnwrapOrInternal(Bool, Int, Int): Int {
+        forall (X: Int, D: Int) {
+            [True, X, D] => X
+            [False, X, D] => D
+        }
     }
 
     /// Integer negation. In balanced ternary, this is just taking every "minus" and "plus" and
@@ -77,8 +428,13 @@ type_operators! {
     ///
     /// Here, we also combine it with the functionality of the `Unique` operator such that they
     /// don't need to be nested (for example in our implementation of subtraction.)
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Neg<{Self}>`",
+        label = "`Neg` is not implemented for these arguments",
+        note = "`Neg` takes an `Int`",
+        note = "in generic code, add `{Self}: IntNeg` to the `where` clause"
+    )]
     (Neg) IntNeg(Int): Int {
-        [Undefined] => Undefined
         [Term] => Term
         [(Zero Term)] => Term
         forall (N: Int) {
@@ -88,14 +444,208 @@ type_operators! {
             [(Plus N)] => (Minus (# N))
             [(Minus N)] => (Plus (# N))
         }
+        forall (U: IntError) {
+            [U] => U
+        }
+    }
+
+    /// Trit-wise negation of an integer, which is just `Neg`. Used as `TritNeg<X>` or
+    /// `<X as IntTritNeg>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `TritNeg<{Self}>`",
+        label = "`TritNeg` is not implemented for these arguments",
+        note = "`TritNeg` takes an `Int`",
+        note = "in generic code, add `{Self}: IntTritNeg` to the `where` clause"
+    )]
+    (TritNeg) IntTritNeg(Int): Int {
+        forall (N: Int) {
+            [N] => (@IntNeg N)
+        }
+    }
+
+    /// Trit-wise minimum of two integers, the ternary analogue of bitwise AND. Used as
+    /// `TritMin<X, Y>` or `<X as IntTritMin<Y>>::Output`. The shorter integer is padded with zero
+    /// trits, and the result is canonical.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `TritMin<{Self}, {B}>`",
+        label = "`TritMin` is not implemented for these arguments",
+        note = "`TritMin` takes two `Int`s",
+        note = "in generic code, add `{Self}: IntTritMin<{B}>` to the `where` clause"
+    )]
+    (TritMin) IntTritMin(Int, Int): Int {
+        [Term, Term] => Term
+        forall (N: Int) {
+            [Term, (Zero N)] => (# (Zero Term) (Zero N))
+            [Term, (Plus N)] => (# (Zero Term) (Plus N))
+            [Term, (Minus N)] => (# (Zero Term) (Minus N))
+            [(Zero N), Term] => (# (Zero N) (Zero Term))
+            [(Plus N), Term] => (# (Plus N) (Zero Term))
+            [(Minus N), Term] => (# (Minus N) (Zero Term))
+        }
+        forall (M: Int, N: Int) {
+            [(Zero M), (Zero N)] => (@Unique (Zero (# M N)))
+            [(Zero M), (Plus N)] => (@Unique (Zero (# M N)))
+            [(Zero M), (Minus N)] => (Minus (# M N))
+            [(Plus M), (Zero N)] => (@Unique (Zero (# M N)))
+            [(Plus M), (Plus N)] => (Plus (# M N))
+            [(Plus M), (Minus N)] => (Minus (# M N))
+            [(Minus M), (Zero N)] => (Minus (# M N))
+            [(Minus M), (Plus N)] => (Minus (# M N))
+            [(Minus M), (Minus N)] => (Minus (# M N))
+        }
+        forall (Y: Int, U: IntError) {
+            [U, Y] => U
+        }
+        forall (U: IntError) {
+            [Term, U] => U
+        }
+        forall (N: Int, U: IntError) {
+            [(Zero N), U] => U
+            [(Plus N), U] => U
+            [(Minus N), U] => U
+        }
+    }
+
+    /// Trit-wise maximum of two integers, the ternary analogue of bitwise OR. Used as
+    /// `TritMax<X, Y>` or `<X as IntTritMax<Y>>::Output`. The shorter integer is padded with zero
+    /// trits, and the result is canonical.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `TritMax<{Self}, {B}>`",
+        label = "`TritMax` is not implemented for these arguments",
+        note = "`TritMax` takes two `Int`s",
+        note = "in generic code, add `{Self}: IntTritMax<{B}>` to the `where` clause"
+    )]
+    (TritMax) IntTritMax(Int, Int): Int {
+        [Term, Term] => Term
+        forall (N: Int) {
+            [Term, (Zero N)] => (# (Zero Term) (Zero N))
+            [Term, (Plus N)] => (# (Zero Term) (Plus N))
+            [Term, (Minus N)] => (# (Zero Term) (Minus N))
+            [(Zero N), Term] => (# (Zero N) (Zero Term))
+            [(Plus N), Term] => (# (Plus N) (Zero Term))
+            [(Minus N), Term] => (# (Minus N) (Zero Term))
+        }
+        forall (M: Int, N: Int) {
+            [(Zero M), (Zero N)] => (@Unique (Zero (# M N)))
+            [(Zero M), (Plus N)] => (Plus (# M N))
+            [(Zero M), (Minus N)] => (@Unique (Zero (# M N)))
+            [(Plus M), (Zero N)] => (Plus (# M N))
+            [(Plus M), (Plus N)] => (Plus (# M N))
+            [(Plus M), (Minus N)] => (Plus (# M N))
+            [(Minus M), (Zero N)] => (@Unique (Zero (# M N)))
+            [(Minus M), (Plus N)] => (Plus (# M N))
+            [(Minus M), (Minus N)] => (Minus (# M N))
+        }
+        forall (Y: Int, U: IntError) {
+            [U, Y] => U
+        }
+        forall (U: IntError) {
+            [Term, U] => U
+        }
+        forall (N: Int, U: IntError) {
+            [(Zero N), U] => U
+            [(Plus N), U] => U
+            [(Minus N), U] => U
+        }
+    }
+
+    /// Trit-wise product of two integers, the ternary analogue of bitwise XNOR: each trit of the
+    /// result is the product of the corresponding trits. Used as `TritMul<X, Y>` or
+    /// `<X as IntTritMul<Y>>::Output`. The shorter integer is padded with zero trits, and the
+    /// result is canonical.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `TritMul<{Self}, {B}>`",
+        label = "`TritMul` is not implemented for these arguments",
+        note = "`TritMul` takes two `Int`s",
+        note = "in generic code, add `{Self}: IntTritMul<{B}>` to the `where` clause"
+    )]
+    (TritMul) IntTritMul(Int, Int): Int {
+        [Term, Term] => Term
+        forall (N: Int) {
+            [Term, (Zero N)] => (# (Zero Term) (Zero N))
+            [Term, (Plus N)] => (# (Zero Term) (Plus N))
+            [Term, (Minus N)] => (# (Zero Term) (Minus N))
+            [(Zero N), Term] => (# (Zero N) (Zero Term))
+            [(Plus N), Term] => (# (Plus N) (Zero Term))
+            [(Minus N), Term] => (# (Minus N) (Zero Term))
+        }
+        forall (M: Int, N: Int) {
+            [(Zero M), (Zero N)] => (@Unique (Zero (# M N)))
+            [(Zero M), (Plus N)] => (@Unique (Zero (# M N)))
+            [(Zero M), (Minus N)] => (@Unique (Zero (# M N)))
+            [(Plus M), (Zero N)] => (@Unique (Zero (# M N)))
+            [(Plus M), (Plus N)] => (Plus (# M N))
+            [(Plus M), (Minus N)] => (Minus (# M N))
+            [(Minus M), (Zero N)] => (@Unique (Zero (# M N)))
+            [(Minus M), (Plus N)] => (Minus (# M N))
+            [(Minus M), (Minus N)] => (Plus (# M N))
+        }
+        forall (Y: Int, U: IntError) {
+            [U, Y] => U
+        }
+        forall (U: IntError) {
+            [Term, U] => U
+        }
+        forall (N: Int, U: IntError) {
+            [(Zero N), U] => U
+            [(Plus N), U] => U
+            [(Minus N), U] => U
+        }
+    }
+
+    /// Trit-wise consensus of two integers: each trit of the result is the corresponding trit of
+    /// the arguments where they agree, and zero where they don't. Used as `TritConsensus<X, Y>` or
+    /// `<X as IntTritConsensus<Y>>::Output`. The shorter integer is padded with zero trits, and
+    /// the result is canonical.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `TritConsensus<{Self}, {B}>`",
+        label = "`TritConsensus` is not implemented for these arguments",
+        note = "`TritConsensus` takes two `Int`s",
+        note = "in generic code, add `{Self}: IntTritConsensus<{B}>` to the `where` clause"
+    )]
+    (TritConsensus) IntTritConsensus(Int, Int): Int {
+        [Term, Term] => Term
+        forall (N: Int) {
+            [Term, (Zero N)] => (# (Zero Term) (Zero N))
+            [Term, (Plus N)] => (# (Zero Term) (Plus N))
+            [Term, (Minus N)] => (# (Zero Term) (Minus N))
+            [(Zero N), Term] => (# (Zero N) (Zero Term))
+            [(Plus N), Term] => (# (Plus N) (Zero Term))
+            [(Minus N), Term] => (# (Minus N) (Zero Term))
+        }
+        forall (M: Int, N: Int) {
+            [(Zero M), (Zero N)] => (@Unique (Zero (# M N)))
+            [(Zero M), (Plus N)] => (@Unique (Zero (# M N)))
+            [(Zero M), (Minus N)] => (@Unique (Zero (# M N)))
+            [(Plus M), (Zero N)] => (@Unique (Zero (# M N)))
+            [(Plus M), (Plus N)] => (Plus (# M N))
+            [(Plus M), (Minus N)] => (@Unique (Zero (# M N)))
+            [(Minus M), (Zero N)] => (@Unique (Zero (# M N)))
+            [(Minus M), (Plus N)] => (@Unique (Zero (# M N)))
+            [(Minus M), (Minus N)] => (Minus (# M N))
+        }
+        forall (Y: Int, U: IntError) {
+            [U, Y] => U
+        }
+        forall (U: IntError) {
+            [Term, U] => U
+        }
+        forall (N: Int, U: IntError) {
+            [(Zero N), U] => U
+            [(Plus N), U] => U
+            [(Minus N), U] => U
+        }
     }
 
     /// Integer addition. Used as `Add<X, Y>` or `<X as IntAdd<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Add<{Self}, {B}>`",
+        label = "`Add` is not implemented for these arguments",
+        note = "`Add` takes two `Int`s",
+        note = "in generic code, add `{Self}: IntAdd<{B}>` to the `where` clause"
+    )]
     (Add) IntAdd(Int, Int): Int {
         [Term, Term] => Term
-        [Undefined, Term] => Undefined
-        [Term, Undefined] => Undefined
-        [Undefined, Undefined] => Undefined
         forall (N: Int) {
             [Term, (Zero N)] => (@Unique (Zero N))
             [Term, (Plus N)] => (Plus N)
@@ -103,12 +653,6 @@ type_operators! {
             [(Zero N), Term] => (@Unique (Zero N))
             [(Plus N), Term] => (Plus N)
             [(Minus N), Term] => (Minus N)
-            [Undefined, (Zero N)] => Undefined
-            [Undefined, (Plus N)] => Undefined
-            [Undefined, (Minus N)] => Undefined
-            [(Zero N), Undefined] => Undefined
-            [(Plus N), Undefined] => Undefined
-            [(Minus N), Undefined] => Undefined
         }
         forall (M: Int, N: Int) {
             [(Zero M), (Zero N)] => (@Unique (Zero (# M N)))
@@ -121,14 +665,28 @@ type_operators! {
             [(Minus M), (Plus N)] => (@Unique (Zero (# M N)))
             [(Minus M), (Minus N)] => (Plus (# (# M N) Minus))
         }
+        forall (Y: Int, U: IntError) {
+            [U, Y] => U
+        }
+        forall (U: IntError) {
+            [Term, U] => U
+        }
+        forall (N: Int, U: IntError) {
+            [(Zero N), U] => U
+            [(Plus N), U] => U
+            [(Minus N), U] => U
+        }
     }
 
     /// Integer subtraction. Used as `Sub<X, Y>` or `<X as IntSub<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Sub<{Self}, {B}>`",
+        label = "`Sub` is not implemented for these arguments",
+        note = "`Sub` takes two `Int`s",
+        note = "in generic code, add `{Self}: IntSub<{B}>` to the `where` clause"
+    )]
     (Sub) IntSub(Int, Int): Int {
         [Term, Term] => Term
-        [Undefined, Term] => Undefined
-        [Term, Undefined] => Undefined
-        [Undefined, Undefined] => Undefined
         forall (N: Int) {
             [Term, (Zero N)] => (@IntNeg (Zero N))
             [Term, (Plus N)] => (Minus (@IntNeg N))
@@ -136,12 +694,6 @@ type_operators! {
             [(Zero N), Term] => (@Unique (Zero N))
             [(Plus N), Term] => (Plus N)
             [(Minus N), Term] => (Minus N)
-            [Undefined, (Zero N)] => Undefined
-            [Undefined, (Plus N)] => Undefined
-            [Undefined, (Minus N)] => Undefined
-            [(Zero N), Undefined] => Undefined
-            [(Plus N), Undefined] => Undefined
-            [(Minus N), Undefined] => Undefined
         }
         forall (M: Int, N: Int) {
             [(Zero M), (Zero N)] => (@Unique (Zero (# M N)))
@@ -154,14 +706,28 @@ type_operators! {
             [(Minus M), (Plus N)] => (Plus (# (# M N) Plus))
             [(Minus M), (Minus N)] => (@Unique (Zero (# M N)))
         }
+        forall (Y: Int, U: IntError) {
+            [U, Y] => U
+        }
+        forall (U: IntError) {
+            [Term, U] => U
+        }
+        forall (N: Int, U: IntError) {
+            [(Zero N), U] => U
+            [(Plus N), U] => U
+            [(Minus N), U] => U
+        }
     }
 
     /// Integer multiplication. Used as `Mul<X, Y>` or `<X as IntMul<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Mul<{Self}, {B}>`",
+        label = "`Mul` is not implemented for these arguments",
+        note = "`Mul` takes two `Int`s",
+        note = "in generic code, add `{Self}: IntMul<{B}>` to the `where` clause"
+    )]
     (Mul) IntMul(Int, Int): Int {
         [Term, Term] => Term
-        [Undefined, Term] => Undefined
-        [Term, Undefined] => Undefined
-        [Undefined, Undefined] => Undefined
         forall (N: Int) {
             [Term, (Zero N)] => Term
             [Term, (Plus N)] => Term
@@ -169,16 +735,10 @@ type_operators! {
             [(Zero N), Term] => Term
             [(Plus N), Term] => Term
             [(Minus N), Term] => Term
-            [Undefined, (Zero N)] => Undefined
-            [Undefined, (Plus N)] => Undefined
-            [Undefined, (Minus N)] => Undefined
-            [(Zero N), Undefined] => Undefined
-            [(Plus N), Undefined] => Undefined
-            [(Minus N), Undefined] => Undefined
         }
         forall (M: Int, N: Int) {
             [(Zero M), (Zero N)] => (# M (Zero (Zero N))) // m0 * n0 => 3 * (m * n0) + 0
-            [(Zero M), (Plus N)] => (# M (Zero (Plus M))) // m0 * n1 => 3 * (m * n1) + 0
+            [(Zero M), (Plus N)] => (# M (Zero (Plus N))) // m0 * n1 => 3 * (m * n1) + 0
             [(Zero M), (Minus N)] => (# M (Zero (Minus N))) // m0 * nT => 3 * (m * nT) + 0
             [(Plus M), (Zero N)] => (@IntAdd (# M (Zero (Zero N))) (Zero N)) // m1 * n0 => 3 * (m * n0) + 0
             [(Plus M), (Plus N)] => (@IntAdd (# M (Zero (Plus N))) (Plus N)) // m1 * n1 => 3 * (m * n1) + n1
@@ -187,12 +747,30 @@ type_operators! {
             [(Minus M), (Plus N)] => (@IntSub (# M (Zero (Plus N))) (Plus N)) // mT * n1 => 3 * (m * n1) - n1
             [(Minus M), (Minus N)] => (@IntSub (# M (Zero (Minus N))) (Minus N)) // mT * nT => 3 * (m * nT) - nT
         }
+        forall (Y: Int, U: IntError) {
+            [U, Y] => U
+        }
+        forall (U: IntError) {
+            [Term, U] => U
+        }
+        forall (N: Int, U: IntError) {
+            [(Zero N), U] => U
+            [(Plus N), U] => U
+            [(Minus N), U] => U
+        }
     }
 
     /// Integer comparison. Takes five integers - the first two are to be compared. If the result is
     /// that the first integer is greater than the second, the fifth integer is returned; if the
     /// result is that the two integers are equal, the fourth integer is returned; and if the result
-    /// is that the first integer is less than the second, the third integer is returned.
+    /// is that the first integer is less than the second, the third integer is returned. If either
+    /// of the compared integers is undefined, then so is the result.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Cmp<{Self}, {B}, {C}, {D}, {E}>`",
+        label = "`Cmp` is not implemented for these arguments",
+        note = "`Cmp` takes five `Int`s",
+        note = "in generic code, add `{Self}: IntCmp<{B}, {C}, {D}, {E}>` to the `where` clause"
+    )]
     (Cmp) IntCmp(Int, Int, Int, Int, Int): Int {
         forall (L: Int, E: Int, G: Int) {
             [Term, Term, L, E, G] => E
@@ -216,35 +794,78 @@ type_operators! {
             [(Minus M), (Plus N), L, E, G] => (# M N L L G)
             [(Minus M), (Minus N), L, E, G] => (# M N L E G)
         }
+        forall (Y: Int, L: Int, E: Int, G: Int, U: IntError) {
+            [U, Y, L, E, G] => U
+        }
+        forall (L: Int, E: Int, G: Int, U: IntError) {
+            [Term, U, L, E, G] => U
+        }
+        forall (N: Int, L: Int, E: Int, G: Int, U: IntError) {
+            [(Zero N), U, L, E, G] => U
+            [(Plus N), U, L, E, G] => U
+            [(Minus N), U, L, E, G] => U
+        }
+    }
+
+    /// Three-way integer comparison, producing an `Ordering`. Used as `Compare<X, Y>` or
+    /// `<X as IntCompare<Y>>::Output`. Unlike `Cmp`, the result can be used to select between
+    /// types of any kind with `Match`. Not implemented for undefined arguments.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Compare<{Self}, {B}>`",
+        label = "`Compare` is not implemented for these arguments",
+        note = "`Compare` takes two `Int`s",
+        note = "`Compare` has no result for `DivByZero` or `Undefined`; `Cmp` passes them along \
+            instead, and `IsDefined` tests for them",
+        note = "in generic code, add `{Self}: IntCompare<{B}>` to the `where` clause"
+    )]
+    (Compare) IntCompare(Int, Int): Ordering {
+        forall (X: Int, Y: Int) {
+            [X, Y] => (@IntCompareInternal X Y Equal)
+        }
+    }
+
+    /// Compare two integers trit by trit, from least to most significant. The third argument is
+    /// the ordering of the trits seen so far, which holds unless a more significant trit differs.
+    (CompareInternal) IntCompareInternal(Int, Int, Ordering): Ordering {
+        forall (O: Ordering) {
+            [Term, Term, O] => O
+        }
+        forall (X: Int, O: Ordering) {
+            [Term, (Zero X), O] => (# Term X O)
+            [Term, (Plus X), O] => (# Term X Less)
+            [Term, (Minus X), O] => (# Term X Greater)
+            [(Zero X), Term, O] => (# X Term O)
+            [(Plus X), Term, O] => (# X Term Greater)
+            [(Minus X), Term, O] => (# X Term Less)
+        }
+        forall (X: Int, Y: Int, O: Ordering) {
+            [(Zero X), (Zero Y), O] => (# X Y O)
+            [(Zero X), (Plus Y), O] => (# X Y Less)
+            [(Zero X), (Minus Y), O] => (# X Y Greater)
+            [(Plus X), (Zero Y), O] => (# X Y Greater)
+            [(Plus X), (Plus Y), O] => (# X Y O)
+            [(Plus X), (Minus Y), O] => (# X Y Greater)
+            [(Minus X), (Zero Y), O] => (# X Y Less)
+            [(Minus X), (Plus Y), O] => (# X Y Less)
+            [(Minus X), (Minus Y), O] => (# X Y O)
+        }
     }
 
     /// Two-way integer comparison, but with the output trait bounds as an
     /// `IntPair` instead of an `Int`.
     (Cmp2) IntCmp2(Int, Int, IntPair, IntPair, IntPair): IntPair {
-        forall (L: IntPair, E: IntPair, G: IntPair) {
-            [Term, Term, L, E, G] => E
-        }
-        forall (N: Int, L: IntPair, E: IntPair, G: IntPair) {
-            [Term, (Zero N), L, E, G] => (# Term N L E G)
-            [Term, (Plus N), L, E, G] => (# Term N L L G)
-            [Term, (Minus N), L, E, G] => (# Term N L G G)
-            [(Zero N), Term, L, E, G] => (# N Term L E G)
-            [(Plus N), Term, L, E, G] => (# N Term L G G)
-            [(Minus N), Term, L, E, G] => (# N Term L L G)
-        }
-        forall (M: Int, N: Int, L: IntPair, E: IntPair, G: IntPair) {
-            [(Zero M), (Zero N), L, E, G] => (# M N L E G)
-            [(Zero M), (Plus N), L, E, G] => (# M N L L G)
-            [(Zero M), (Minus N), L, E, G] => (# M N L G G)
-            [(Plus M), (Zero N), L, E, G] => (# M N L G G)
-            [(Plus M), (Plus N), L, E, G] => (# M N L E G)
-            [(Plus M), (Minus N), L, E, G] => (# M N L G G)
-            [(Minus M), (Zero N), L, E, G] => (# M N L L G)
-            [(Minus M), (Plus N), L, E, G] => (# M N L L G)
-            [(Minus M), (Minus N), L, E, G] => (# M N L E G)
+        forall (X: Int, Y: Int, L: IntPair, E: IntPair, G: IntPair) {
+            [X, Y, L, E, G] => (& (@OrdMatch (@IntCompare X Y) L E G)
+                where (Match<Compare<X, Y>, L, E, G>: IntPair))
         }
     }
 
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `LteCmp<{Self}, {B}, {C}, {D}>`",
+        label = "`LteCmp` is not implemented for these arguments",
+        note = "`LteCmp` takes four `Int`s",
+        note = "in generic code, add `{Self}: IntLteCmp<{B}, {C}, {D}>` to the `where` clause"
+    )]
     (LteCmp) IntLteCmp(Int, Int, Int, Int): Int {
         forall (X: Int, Y: Int, LE: Int, G: Int) {
             [X, Y, LE, G] => (@IntCmp X Y LE LE G)
@@ -257,14 +878,230 @@ type_operators! {
         }
     }
 
+    /// The lesser of two integers. Used as `Min<X, Y>` or `<X as IntMin<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Min<{Self}, {B}>`",
+        label = "`Min` is not implemented for these arguments",
+        note = "`Min` takes two `Int`s",
+        note = "in generic code, add `{Self}: IntMin<{B}>` to the `where` clause"
+    )]
+    (Min) IntMin(Int, Int): Int {
+        forall (X: Int, Y: Int) {
+            [X, Y] => (@IntCmp X Y X X Y)
+        }
+    }
+
+    /// The greater of two integers. Used as `Max<X, Y>` or `<X as IntMax<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Max<{Self}, {B}>`",
+        label = "`Max` is not implemented for these arguments",
+        note = "`Max` takes two `Int`s",
+        note = "in generic code, add `{Self}: IntMax<{B}>` to the `where` clause"
+    )]
+    (Max) IntMax(Int, Int): Int {
+        forall (X: Int, Y: Int) {
+            [X, Y] => (@IntCmp X Y Y Y X)
+        }
+    }
+
+    /// Restrict an integer to an interval. Used as `Clamp<X, Lo, Hi>` or
+    /// `<X as IntClamp<Lo, Hi>>::Output`. Returns `Lo` if `X` is less than `Lo`, `Hi` if `X` is
+    /// greater than `Hi`, and `X` otherwise. Undefined if `Lo` is greater than `Hi`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Clamp<{Self}, {B}, {C}>`",
+        label = "`Clamp` is not implemented for these arguments",
+        note = "`Clamp` takes three `Int`s",
+        note = "if `{B}` is greater than `{C}`, the interval is empty and the result is \
+            `Undefined`",
+        note = "in generic code, add `{Self}: IntClamp<{B}, {C}>` to the `where` clause"
+    )]
+    (Clamp) IntClamp(Int, Int, Int): Int {
+        forall (X: Int, Lo: Int, Hi: Int) {
+            [X, Lo, Hi] => (@IntCmp Lo Hi
+                (@IntMin (@IntMax X Lo) Hi)
+                (@IntMin (@IntMax X Lo) Hi)
+                Undefined)
+        }
+    }
+
+    /// The sign of an integer: `SN1` if it is negative, `S0` if it is zero and `SP1` if it is
+    /// positive. Used as `Signum<X>` or `<X as IntSignum>::Output`. Since the sign of a balanced
+    /// ternary integer is the sign of its most significant nonzero trit, this is much cheaper than
+    /// comparing with zero.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Signum<{Self}>`",
+        label = "`Signum` is not implemented for these arguments",
+        note = "`Signum` takes an `Int`",
+        note = "in generic code, add `{Self}: IntSignum` to the `where` clause"
+    )]
+    (Signum) IntSignum(Int): Int {
+        forall (N: Int) {
+            [N] => (@IntSignumInternal N Term)
+        }
+    }
+
+    /// Walk the trits of an integer from least to most significant, keeping the sign of the last
+    /// nonzero trit seen.
+    (SignumInternal) IntSignumInternal(Int, Int): Int {
+        forall (S: Int) {
+            [Term, S] => S
+        }
+        forall (N: Int, S: Int) {
+            [(Zero N), S] => (# N S)
+            [(Plus N), S] => (# N Plus)
+            [(Minus N), S] => (# N Minus)
+        }
+        forall (S: Int, U: IntError) {
+            [U, S] => U
+        }
+    }
+
+    /// The sign of an integer as an `Ordering`: `Less` if it is negative, `Equal` if it is zero
+    /// and `Greater` if it is positive. Used as `Sign<X>` or `<X as IntSign>::Output`. This is the
+    /// same as `Compare<X, S0>`, but computed with `Signum`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Sign<{Self}>`",
+        label = "`Sign` is not implemented for these arguments",
+        note = "`Sign` takes an `Int`",
+        note = "in generic code, add `{Self}: IntSign` to the `where` clause"
+    )]
+    (Sign) IntSign(Int): Ordering {
+        forall (N: Int) {
+            [N] => (@IntSignInternal (@IntSignum N))
+        }
+    }
+
+    (SignInternal) IntSignInternal(Int): Ordering {
+        [Minus] => Less
+        [Term] => Equal
+        [Plus] => Greater
+    }
+
+    /// Whether an integer is less than zero. Used as `IsNegative<X>` or
+    /// `<X as IntIsNegative>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IsNegative<{Self}>`",
+        label = "`IsNegative` is not implemented for these arguments",
+        note = "`IsNegative` takes an `Int`",
+        note = "in generic code, add `{Self}: IntIsNegative` to the `where` clause"
+    )]
+    (IsNegative) IntIsNegative(Int): Bool {
+        forall (N: Int) {
+            [N] => (& (@OrdMatch (@IntSign N) True False False)
+                where (Match<Sign<N>, True, False, False>: Bool))
+        }
+    }
+
+    /// Whether an integer is greater than zero. Used as `IsPositive<X>` or
+    /// `<X as IntIsPositive>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IsPositive<{Self}>`",
+        label = "`IsPositive` is not implemented for these arguments",
+        note = "`IsPositive` takes an `Int`",
+        note = "in generic code, add `{Self}: IntIsPositive` to the `where` clause"
+    )]
+    (IsPositive) IntIsPositive(Int): Bool {
+        forall (N: Int) {
+            [N] => (& (@OrdMatch (@IntSign N) False False True)
+                where (Match<Sign<N>, False, False, True>: Bool))
+        }
+    }
+
+    /// Whether an integer is zero. Used as `IsZero<X>` or `<X as IntIsZero>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IsZero<{Self}>`",
+        label = "`IsZero` is not implemented for these arguments",
+        note = "`IsZero` takes an `Int`",
+        note = "in generic code, add `{Self}: IntIsZero` to the `where` clause"
+    )]
+    (IsZero) IntIsZero(Int): Bool {
+        forall (N: Int) {
+            [N] => (& (@OrdMatch (@IntSign N) False True False)
+                where (Match<Sign<N>, False, True, False>: Bool))
+        }
+    }
+
+    /// Whether an integer is even. Used as `IsEven<X>` or `<X as IntIsEven>::Output`. Every power
+    /// of three is odd, so an integer is even exactly when it has an even number of nonzero trits.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IsEven<{Self}>`",
+        label = "`IsEven` is not implemented for these arguments",
+        note = "`IsEven` takes an `Int`",
+        note = "`DivByZero` and `Undefined` have no parity, so `IsEven` is not implemented for \
+            them; test with `IsDefined` first",
+        note = "in generic code, add `{Self}: IntIsEven` to the `where` clause"
+    )]
+    (IsEven) IntIsEven(Int): Bool {
+        [Term] => True
+        forall (N: Int) {
+            [(Zero N)] => (# N)
+            [(Plus N)] => (@BoolNot (# N))
+            [(Minus N)] => (@BoolNot (# N))
+        }
+    }
+
+    /// Whether an integer is odd. Used as `IsOdd<X>` or `<X as IntIsOdd>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IsOdd<{Self}>`",
+        label = "`IsOdd` is not implemented for these arguments",
+        note = "`IsOdd` takes an `Int`",
+        note = "in generic code, add `{Self}: IntIsOdd` to the `where` clause"
+    )]
+    (IsOdd) IntIsOdd(Int): Bool {
+        forall (N: Int) {
+            [N] => (@BoolNot (@IntIsEven N))
+        }
+    }
+
     /// Integer absolute value. Used as `Abs<X>` or `<X as IntAbs>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Abs<{Self}>`",
+        label = "`Abs` is not implemented for these arguments",
+        note = "`Abs` takes an `Int`",
+        note = "in generic code, add `{Self}: IntAbs` to the `where` clause"
+    )]
     (Abs) IntAbs(Int): Int {
-        [Term] => Term
-        [Undefined] => Undefined
-        forall (X: Int) {
-            [(Zero X)] => (@IntLteCmp Term (Zero X) (Zero X) (@IntNeg (Zero X)))
-            [(Plus X)] => (@IntLteCmp Term (Plus X) (Plus X) (@IntNeg (Plus X)))
-            [(Minus X)] => (@IntLteCmp Term (Minus X) (Minus X) (@IntNeg (Minus X)))
+        forall (N: Int) {
+            [N] => (@IntAbsInternal (@IntSignum N) N)
+        }
+    }
+
+    (AbsInternal) IntAbsInternal(Int, Int): Int {
+        forall (N: Int) {
+            [Minus, N] => (@IntNeg N)
+            [Term, N] => N
+            [Plus, N] => N
+        }
+        forall (N: Int, U: IntError) {
+            [U, N] => U
+        }
+    }
+
+    /// Of two integers, the one with the lesser absolute value. Used as `MinAbs<X, Y>` or
+    /// `<X as IntMinAbs<Y>>::Output`. If the absolute values are equal, `X` is returned.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `MinAbs<{Self}, {B}>`",
+        label = "`MinAbs` is not implemented for these arguments",
+        note = "`MinAbs` takes two `Int`s",
+        note = "in generic code, add `{Self}: IntMinAbs<{B}>` to the `where` clause"
+    )]
+    (MinAbs) IntMinAbs(Int, Int): Int {
+        forall (X: Int, Y: Int) {
+            [X, Y] => (@IntCmp (@IntAbs X) (@IntAbs Y) X X Y)
+        }
+    }
+
+    /// Of two integers, the one with the greater absolute value. Used as `MaxAbs<X, Y>` or
+    /// `<X as IntMaxAbs<Y>>::Output`. If the absolute values are equal, `X` is returned.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `MaxAbs<{Self}, {B}>`",
+        label = "`MaxAbs` is not implemented for these arguments",
+        note = "`MaxAbs` takes two `Int`s",
+        note = "in generic code, add `{Self}: IntMaxAbs<{B}>` to the `where` clause"
+    )]
+    (MaxAbs) IntMaxAbs(Int, Int): Int {
+        forall (X: Int, Y: Int) {
+            [X, Y] => (@IntCmp (@IntAbs X) (@IntAbs Y) Y X X)
         }
     }
 
@@ -278,15 +1115,25 @@ type_operators! {
         }
     }
 
-    /// Integer *reversal.* This is probably something you *never* want to do! However, it's useful
-    /// in implementing division, in which we wish to access trits with the most-significant trits
-    /// first. This reverses all the trits of the integer.
+    /// Integer *reversal.* This is probably something you *never* want to do! It reverses all the
+    /// trits of the integer, and then removes any leading zeroes so that the result is canonical;
+    /// for example, `Rev<SP3> = SP1`. To work with the trits of a number as an explicit list,
+    /// possibly with leading zeroes, see the `TritSeq` kind in the `trits` module.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Rev<{Self}>`",
+        label = "`Rev` is not implemented for these arguments",
+        note = "`Rev` takes an `Int`",
+        note = "in generic code, add `{Self}: IntRev` to the `where` clause"
+    )]
     (Rev) IntRev(Int): Int {
         forall (N: Int) {
-            [N] => (@IntRevInternal N Term)
+            [N] => (@IntNormalize (@IntRevInternal N Term))
         }
     }
 
+    /// Reverse the trits of the first argument onto the front of the second. The result keeps
+    /// any zeroes which were least significant in the original, which is what division needs, but
+    /// is not canonical.
     (RevInternal) IntRevInternal(Int, Int): Int {
         forall (N: Int) {
             [Term, N] => N
@@ -298,51 +1145,281 @@ type_operators! {
         }
     }
 
-    /// Integer division. Used as `Div<X, Y>` or `<X as IntDiv<Y>>::Output`.
+    /// Integer division. Used as `Div<X, Y>` or `<X as IntDiv<Y>>::Output`. Unlike Rust's `/`
+    /// operator, this rounds to the nearest integer, since that is what falls out naturally from
+    /// balanced ternary long division; it is the same as `DivRound`. Where the exact quotient is a
+    /// half-integer, it may round either way. For other rounding modes, see `DivTrunc`,
+    /// `DivFloor`, `DivCeil` and `DivEuclid`. Every division operator returns `DivByZero` in the
+    /// case of a division by zero.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Div<{Self}, {B}>`",
+        label = "`Div` is not implemented for these arguments",
+        note = "`Div` takes two `Int`s",
+        note = "`Div<{Self}, S0>` is the error `DivByZero`; a `Defined` bound on the result \
+            rejects it at compile time",
+        note = "only a canonical `S0` is caught as a zero divisor: a hand-written zero such as \
+            `Zero<Term>` gives a meaningless quotient, so `Normalize` it first",
+        note = "in generic code, add `{Self}: IntDiv<{B}>` to the `where` clause"
+    )]
     (Div) IntDiv(Int, Int): Int {
-        [Term, Term] => Undefined
-        [Term, Undefined] => Undefined
-        [Undefined, Term] => Undefined
-        [Undefined, Undefined] => Undefined
-        forall (D: Int) {
-            [Term, (Zero D)] => Term
-            [Term, (Plus D)] => Term
-            [Term, (Minus D)] => Term
-        }
         forall (N: Int, D: Int) {
-            [(Zero N), (Zero D)] => (@Int2P2 (@IntDivInternal (@IntRev (Zero N)) (Zero D) (Int2 Term Term)))
-            [(Zero N), (Plus D)] => (@Int2P2 (@IntDivInternal (@IntRev (Zero N)) (Plus D) (Int2 Term Term)))
-            [(Zero N), (Minus D)] => (@Int2P2 (@IntDivInternal (@IntRev (Zero N)) (Minus D) (Int2 Term Term)))
-            [(Plus N), (Zero D)] => (@Int2P2 (@IntDivInternal (@IntRev (Plus N)) (Zero D) (Int2 Term Term)))
-            [(Plus N), (Plus D)] => (@Int2P2 (@IntDivInternal (@IntRev (Plus N)) (Plus D) (Int2 Term Term)))
-            [(Plus N), (Minus D)] => (@Int2P2 (@IntDivInternal (@IntRev (Plus N)) (Minus D) (Int2 Term Term)))
-            [(Minus N), (Zero D)] => (@Int2P2 (@IntDivInternal (@IntRev (Minus N)) (Zero D) (Int2 Term Term)))
-            [(Minus N), (Plus D)] => (@Int2P2 (@IntDivInternal (@IntRev (Minus N)) (Plus D) (Int2 Term Term)))
-            [(Minus N), (Minus D)] => (@Int2P2 (@IntDivInternal (@IntRev (Minus N)) (Minus D) (Int2 Term Term)))
+            [N, D] => (@Int2P2 (@IntDivRemInternal N D))
         }
     }
 
-    /// Integer remainder. Used as `Rem<X, Y>` or `<X as IntRem<Y>>::Output`.
+    /// Integer remainder. Used as `Rem<X, Y>` or `<X as IntRem<Y>>::Output`. This is the
+    /// remainder left by `Div`, and so is the remainder of least absolute value; it is the same
+    /// as `RemRound`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Rem<{Self}, {B}>`",
+        label = "`Rem` is not implemented for these arguments",
+        note = "`Rem` takes two `Int`s",
+        note = "as with `Div`, a zero divisor gives `DivByZero`, and one written non-canonically, \
+            such as `Zero<Term>`, is not caught at all",
+        note = "in generic code, add `{Self}: IntRem<{B}>` to the `where` clause"
+    )]
     (Rem) IntRem(Int, Int): Int {
-        [Term, Term] => Undefined
-        [Term, Undefined] => Undefined
-        [Undefined, Term] => Undefined
-        [Undefined, Undefined] => Undefined
-        forall (D: Int) {
-            [Term, (Zero D)] => Term
-            [Term, (Plus D)] => Term
-            [Term, (Minus D)] => Term
+        forall (N: Int, D: Int) {
+            [N, D] => (@Int2P1 (@IntDivRemInternal N D))
+        }
+    }
+
+    /// Integer division with remainder. Used as `DivRem<X, Y>` or `<X as IntDivRem<Y>>::Output`.
+    /// The result is an `IntPair` with the quotient first and the remainder second, as given by
+    /// `Div` and `Rem`, but computed in a single pass.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `DivRem<{Self}, {B}>`",
+        label = "`DivRem` is not implemented for these arguments",
+        note = "`DivRem` takes two `Int`s",
+        note = "dividing by `S0` gives `Int2<DivByZero, DivByZero>`; take the halves apart with \
+            `Int2First` and `Int2Second` to bound either with `Defined`",
+        note = "a zero divisor written non-canonically, such as `Zero<Term>`, is not caught; \
+            `Normalize` hand-written divisors",
+        note = "in generic code, add `{Self}: IntDivRem<{B}>` to the `where` clause"
+    )]
+    (DivRem) IntDivRem(Int, Int): IntPair {
+        forall (N: Int, D: Int) {
+            [N, D] => (@Int2Sw (@IntDivRemInternal N D))
+        }
+    }
+
+    /// Integer division, rounding to the nearest integer. Used as `DivRound<X, Y>` or
+    /// `<X as IntDivRound<Y>>::Output`. Where the exact quotient is a half-integer, it may round
+    /// either way.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `DivRound<{Self}, {B}>`",
+        label = "`DivRound` is not implemented for these arguments",
+        note = "`DivRound` takes two `Int`s",
+        note = "`DivRound<{Self}, S0>` is `DivByZero`, as for `Div`",
+        note = "in generic code, add `{Self}: IntDivRound<{B}>` to the `where` clause"
+    )]
+    (DivRound) IntDivRound(Int, Int): Int {
+        forall (N: Int, D: Int) {
+            [N, D] => (@Int2P2 (@IntDivRemInternal N D))
+        }
+    }
+
+    /// The remainder left by `DivRound`. Used as `RemRound<X, Y>` or
+    /// `<X as IntRemRound<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `RemRound<{Self}, {B}>`",
+        label = "`RemRound` is not implemented for these arguments",
+        note = "`RemRound` takes two `Int`s",
+        note = "`RemRound<{Self}, S0>` is `DivByZero`, as for `Rem`",
+        note = "in generic code, add `{Self}: IntRemRound<{B}>` to the `where` clause"
+    )]
+    (RemRound) IntRemRound(Int, Int): Int {
+        forall (N: Int, D: Int) {
+            [N, D] => (@Int2P1 (@IntDivRemInternal N D))
+        }
+    }
+
+    /// Integer division, rounding towards zero. Used as `DivTrunc<X, Y>` or
+    /// `<X as IntDivTrunc<Y>>::Output`. This agrees with Rust's `/` operator.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `DivTrunc<{Self}, {B}>`",
+        label = "`DivTrunc` is not implemented for these arguments",
+        note = "`DivTrunc` takes two `Int`s",
+        note = "where Rust's `/` panics on a zero divisor, `DivTrunc<{Self}, S0>` is `DivByZero`",
+        note = "in generic code, add `{Self}: IntDivTrunc<{B}>` to the `where` clause"
+    )]
+    (DivTrunc) IntDivTrunc(Int, Int): Int {
+        forall (N: Int, D: Int) {
+            [N, D] => (@Int2P2 (@IntDivRemAdjust (@IntDivRemInternal N D) (@IntSignum N) D))
+        }
+    }
+
+    /// The remainder left by `DivTrunc`, which has the same sign as the dividend. Used as
+    /// `RemTrunc<X, Y>` or `<X as IntRemTrunc<Y>>::Output`. This agrees with Rust's `%` operator.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `RemTrunc<{Self}, {B}>`",
+        label = "`RemTrunc` is not implemented for these arguments",
+        note = "`RemTrunc` takes two `Int`s",
+        note = "where Rust's `%` panics on a zero divisor, `RemTrunc<{Self}, S0>` is `DivByZero`",
+        note = "in generic code, add `{Self}: IntRemTrunc<{B}>` to the `where` clause"
+    )]
+    (RemTrunc) IntRemTrunc(Int, Int): Int {
+        forall (N: Int, D: Int) {
+            [N, D] => (@Int2P1 (@IntDivRemAdjust (@IntDivRemInternal N D) (@IntSignum N) D))
+        }
+    }
+
+    /// Integer division, rounding towards negative infinity. Used as `DivFloor<X, Y>` or
+    /// `<X as IntDivFloor<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `DivFloor<{Self}, {B}>`",
+        label = "`DivFloor` is not implemented for these arguments",
+        note = "`DivFloor` takes two `Int`s",
+        note = "`DivFloor<{Self}, S0>` is `DivByZero`",
+        note = "in generic code, add `{Self}: IntDivFloor<{B}>` to the `where` clause"
+    )]
+    (DivFloor) IntDivFloor(Int, Int): Int {
+        forall (N: Int, D: Int) {
+            [N, D] => (@Int2P2 (@IntDivRemAdjust (@IntDivRemInternal N D) (@IntSignum D) D))
         }
+    }
+
+    /// The remainder left by `DivFloor`, which has the same sign as the divisor. Used as
+    /// `RemFloor<X, Y>` or `<X as IntRemFloor<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `RemFloor<{Self}, {B}>`",
+        label = "`RemFloor` is not implemented for these arguments",
+        note = "`RemFloor` takes two `Int`s",
+        note = "`RemFloor<{Self}, S0>` is `DivByZero`",
+        note = "in generic code, add `{Self}: IntRemFloor<{B}>` to the `where` clause"
+    )]
+    (RemFloor) IntRemFloor(Int, Int): Int {
+        forall (N: Int, D: Int) {
+            [N, D] => (@Int2P1 (@IntDivRemAdjust (@IntDivRemInternal N D) (@IntSignum D) D))
+        }
+    }
+
+    /// Integer division, rounding towards positive infinity. Used as `DivCeil<X, Y>` or
+    /// `<X as IntDivCeil<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `DivCeil<{Self}, {B}>`",
+        label = "`DivCeil` is not implemented for these arguments",
+        note = "`DivCeil` takes two `Int`s",
+        note = "`DivCeil<{Self}, S0>` is `DivByZero`",
+        note = "in generic code, add `{Self}: IntDivCeil<{B}>` to the `where` clause"
+    )]
+    (DivCeil) IntDivCeil(Int, Int): Int {
+        forall (N: Int, D: Int) {
+            [N, D] => (@Int2P2 (@IntDivRemAdjust (@IntDivRemInternal N D) (@IntNeg (@IntSignum D)) D))
+        }
+    }
+
+    /// The remainder left by `DivCeil`, which has the opposite sign to the divisor. Used as
+    /// `RemCeil<X, Y>` or `<X as IntRemCeil<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `RemCeil<{Self}, {B}>`",
+        label = "`RemCeil` is not implemented for these arguments",
+        note = "`RemCeil` takes two `Int`s",
+        note = "`RemCeil<{Self}, S0>` is `DivByZero`",
+        note = "in generic code, add `{Self}: IntRemCeil<{B}>` to the `where` clause"
+    )]
+    (RemCeil) IntRemCeil(Int, Int): Int {
+        forall (N: Int, D: Int) {
+            [N, D] => (@Int2P1 (@IntDivRemAdjust (@IntDivRemInternal N D) (@IntNeg (@IntSignum D)) D))
+        }
+    }
+
+    /// Euclidean integer division. Used as `DivEuclid<X, Y>` or `<X as IntDivEuclid<Y>>::Output`.
+    /// This agrees with `isize::div_euclid`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `DivEuclid<{Self}, {B}>`",
+        label = "`DivEuclid` is not implemented for these arguments",
+        note = "`DivEuclid` takes two `Int`s",
+        note = "where `isize::div_euclid` panics on a zero divisor, `DivEuclid<{Self}, S0>` is \
+            `DivByZero`",
+        note = "in generic code, add `{Self}: IntDivEuclid<{B}>` to the `where` clause"
+    )]
+    (DivEuclid) IntDivEuclid(Int, Int): Int {
         forall (N: Int, D: Int) {
-            [(Zero N), (Zero D)] => (@Int2P1 (@IntDivInternal (@IntRev (Zero N)) (Zero D) (Int2 Term Term)))
-            [(Zero N), (Plus D)] => (@Int2P1 (@IntDivInternal (@IntRev (Zero N)) (Plus D) (Int2 Term Term)))
-            [(Zero N), (Minus D)] => (@Int2P1 (@IntDivInternal (@IntRev (Zero N)) (Minus D) (Int2 Term Term)))
-            [(Plus N), (Zero D)] => (@Int2P1 (@IntDivInternal (@IntRev (Plus N)) (Zero D) (Int2 Term Term)))
-            [(Plus N), (Plus D)] => (@Int2P1 (@IntDivInternal (@IntRev (Plus N)) (Plus D) (Int2 Term Term)))
-            [(Plus N), (Minus D)] => (@Int2P1 (@IntDivInternal (@IntRev (Plus N)) (Minus D) (Int2 Term Term)))
-            [(Minus N), (Zero D)] => (@Int2P1 (@IntDivInternal (@IntRev (Minus N)) (Zero D) (Int2 Term Term)))
-            [(Minus N), (Plus D)] => (@Int2P1 (@IntDivInternal (@IntRev (Minus N)) (Plus D) (Int2 Term Term)))
-            [(Minus N), (Minus D)] => (@Int2P1 (@IntDivInternal (@IntRev (Minus N)) (Minus D) (Int2 Term Term)))
+            [N, D] => (@Int2P2 (@IntDivRemAdjust (@IntDivRemInternal N D) Plus D))
+        }
+    }
+
+    /// The remainder left by `DivEuclid`, which is never negative. Used as `RemEuclid<X, Y>` or
+    /// `<X as IntRemEuclid<Y>>::Output`. This agrees with `isize::rem_euclid`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `RemEuclid<{Self}, {B}>`",
+        label = "`RemEuclid` is not implemented for these arguments",
+        note = "`RemEuclid` takes two `Int`s",
+        note = "where `isize::rem_euclid` panics on a zero divisor, `RemEuclid<{Self}, S0>` is \
+            `DivByZero`",
+        note = "in generic code, add `{Self}: IntRemEuclid<{B}>` to the `where` clause"
+    )]
+    (RemEuclid) IntRemEuclid(Int, Int): Int {
+        forall (N: Int, D: Int) {
+            [N, D] => (@Int2P1 (@IntDivRemAdjust (@IntDivRemInternal N D) Plus D))
+        }
+    }
+
+    /// Division with remainder, rounding to the nearest integer. The result is an `IntPair` with
+    /// the remainder first and the quotient second.
+    (DivRemInternal) IntDivRemInternal(Int, Int): IntPair {
+        [Term, Term] => (Int2 DivByZero DivByZero)
+        forall (X: Int) {
+            [Term, (Zero X)] => (Int2 Term Term)
+            [Term, (Plus X)] => (Int2 Term Term)
+            [Term, (Minus X)] => (Int2 Term Term)
+            [(Zero X), Term] => (Int2 DivByZero DivByZero)
+            [(Plus X), Term] => (Int2 DivByZero DivByZero)
+            [(Minus X), Term] => (Int2 DivByZero DivByZero)
+        }
+        forall (N: Int, D: Int) {
+            [(Zero N), (Zero D)] => (@IntDivInternal (@IntRevInternal (Zero N) Term) (Zero D) (Int2 Term Term))
+            [(Zero N), (Plus D)] => (@IntDivInternal (@IntRevInternal (Zero N) Term) (Plus D) (Int2 Term Term))
+            [(Zero N), (Minus D)] => (@IntDivInternal (@IntRevInternal (Zero N) Term) (Minus D) (Int2 Term Term))
+            [(Plus N), (Zero D)] => (@IntDivInternal (@IntRevInternal (Plus N) Term) (Zero D) (Int2 Term Term))
+            [(Plus N), (Plus D)] => (@IntDivInternal (@IntRevInternal (Plus N) Term) (Plus D) (Int2 Term Term))
+            [(Plus N), (Minus D)] => (@IntDivInternal (@IntRevInternal (Plus N) Term) (Minus D) (Int2 Term Term))
+            [(Minus N), (Zero D)] => (@IntDivInternal (@IntRevInternal (Minus N) Term) (Zero D) (Int2 Term Term))
+            [(Minus N), (Plus D)] => (@IntDivInternal (@IntRevInternal (Minus N) Term) (Plus D) (Int2 Term Term))
+            [(Minus N), (Minus D)] => (@IntDivInternal (@IntRevInternal (Minus N) Term) (Minus D) (Int2 Term Term))
+        }
+        forall (Y: Int, U: IntError) {
+            [U, Y] => (Int2 U U)
+        }
+        forall (U: IntError) {
+            [Term, U] => (Int2 U U)
+        }
+        forall (X: Int, U: IntError) {
+            [(Zero X), U] => (Int2 U U)
+            [(Plus X), U] => (Int2 U U)
+            [(Minus X), U] => (Int2 U U)
+        }
+    }
+
+    /// Adjust the result of a division with remainder so that the remainder has the given sign
+    /// (or is zero.) Takes the remainder/quotient pair, the sign the remainder should have as one
+    /// of `Minus`, `Term` or `Plus`, and the divisor. Since the remainder of a nearest-rounding
+    /// division is at most half the divisor in absolute value, one step is always enough.
+    (DivRemAdjust) IntDivRemAdjust(IntPair, Int, Int): IntPair {
+        forall (R: Int, Q: Int, S: Int, D: Int) {
+            [(Int2 R Q), S, D] => (@IntDivRemAdjustInternal
+                (@IntSignum R) S (@IntSignum D)
+                (Int2 R Q) D)
+        }
+    }
+
+    (DivRemAdjustInternal) IntDivRemAdjustInternal(Int, Int, Int, IntPair, Int): IntPair {
+        forall (S: Int, T: Int, P: IntPair, D: Int) {
+            [Term, S, T, P, D] => P
+        }
+        forall (T: Int, P: IntPair, D: Int) {
+            [Plus, Plus, T, P, D] => P
+            [Minus, Minus, T, P, D] => P
+        }
+        forall (R: Int, Q: Int, D: Int) {
+            [Plus, Minus, Plus, (Int2 R Q), D] => (Int2 (@IntSub R D) (@IntSucc Q))
+            [Plus, Minus, Minus, (Int2 R Q), D] => (Int2 (@IntAdd R D) (@IntPred Q))
+            [Minus, Plus, Plus, (Int2 R Q), D] => (Int2 (@IntAdd R D) (@IntPred Q))
+            [Minus, Plus, Minus, (Int2 R Q), D] => (Int2 (@IntSub R D) (@IntSucc Q))
+        }
+        forall (S: Int, T: Int, P: IntPair, D: Int, U: IntError) {
+            [U, S, T, P, D] => (Int2 U U)
         }
     }
 
@@ -352,31 +1429,106 @@ type_operators! {
         }
         forall (N: Int, D: Int, R: Int, Q: Int) {
             [(Zero N), D, (Int2 R Q)] => (# N D
-                (@IntAbsMinThreeCmp
-                    (@IntTriple R)
-                    (@IntSub (@IntTriple R) D)
-                    (@IntAdd (@IntTriple R) D)
-                        (@IntTriple Q) (Plus Q) (Minus Q)))
+                (@IntDivRemStep
+                    (@IntAbsMinThreeCmp
+                        (@IntTriple R)
+                        (@IntSub (@IntTriple R) D)
+                        (@IntAdd (@IntTriple R) D)
+                            (@IntTriple Q) (Plus Q) (Minus Q))
+                    D))
             [(Plus N), D, (Int2 R Q)] => (# N D
-                (@IntAbsMinThreeCmp
-                    (Plus R)
-                    (@IntSub (Plus R) D)
-                    (@IntAdd (Plus R) D)
-                        (@IntTriple Q) (Plus Q) (Minus Q)))
+                (@IntDivRemStep
+                    (@IntAbsMinThreeCmp
+                        (Plus R)
+                        (@IntSub (Plus R) D)
+                        (@IntAdd (Plus R) D)
+                            (@IntTriple Q) (Plus Q) (Minus Q))
+                    D))
             [(Minus N), D, (Int2 R Q)] => (# N D
-                (@IntAbsMinThreeCmp
-                    (Minus R)
-                    (@IntSub (Minus R) D)
-                    (@IntAdd (Minus R) D)
-                        (@IntTriple Q) (Plus Q) (Minus Q)))
+                (@IntDivRemStep
+                    (@IntAbsMinThreeCmp
+                        (Minus R)
+                        (@IntSub (Minus R) D)
+                        (@IntAdd (Minus R) D)
+                            (@IntTriple Q) (Plus Q) (Minus Q))
+                    D))
+        }
+    }
+
+    /// Move the remainder of a remainder/quotient pair one multiple of the divisor `D` towards
+    /// zero, if that makes it smaller in absolute value. Each step of `DivInternal` brings in a
+    /// trit and tries moving the remainder by `D` once, which can leave it as large as
+    /// `|D| / 2 + 1`; a second try brings it back to at most `|D| / 2`, and keeps the error from
+    /// growing with every trit.
+    (DivRemStep) IntDivRemStep(IntPair, Int): IntPair {
+        forall (R: Int, Q: Int, D: Int) {
+            [(Int2 R Q), D] => (@IntAbsMinThreeCmp
+                R (@IntSub R D) (@IntAdd R D)
+                Q (@IntSucc Q) (@IntPred Q))
         }
     }
 }
 
 
+/// A marker trait implemented exactly by the canonical `Int`s, those with no redundant leading
+/// zeroes. Use it as a bound to reject non-canonical inputs at compile time; see `IsCanonical`
+/// and `Normalize`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a canonical `Int`",
+    label = "this has redundant leading zeroes",
+    note = "hand-written `Int`s such as `Zero<Term>` can be put into canonical form with \
+        `Normalize`"
+)]
+pub trait Canonical: Int {}
+
+impl<N> Canonical for N
+    where N: IntIsCanonical, <N as IntIsCanonical>::Output: CanonicalHolds {}
+
+/// A marker trait implemented exactly by the defined `Int`s, those which contain no errors. Use it
+/// as a bound to require that the result of an operation such as `Sub` or `Div` is defined, so
+/// that a mistake fails at compile time instead of panicking at `reify`; see `IsDefined`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a defined `Int`",
+    label = "this contains an error value",
+    note = "`DivByZero` is the result of a `Div` or `Rem` by zero, and `Undefined` of any \
+        other operation with no result"
+)]
+pub trait Defined: Int {}
+
+impl<N> Defined for N
+    where N: IntIsDefined, <N as IntIsDefined>::Output: DefinedHolds {}
+
+/// The test underlying `Canonical`, which must come out `True`.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "the `Int` is not canonical",
+    label = "this has redundant leading zeroes",
+    note = "hand-written `Int`s such as `Zero<Term>` can be put into canonical form with \
+        `Normalize`"
+)]
+pub trait CanonicalHolds {}
+
+impl CanonicalHolds for True {}
+
+/// The test underlying `Defined`, which must come out `True`.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "the `Int` is not defined",
+    label = "this contains an error value",
+    note = "`DivByZero` is the result of a `Div` or `Rem` by zero, and `Undefined` of any \
+        other operation with no result"
+)]
+pub trait DefinedHolds {}
+
+impl DefinedHolds for True {}
+
+
 #[allow(non_snake_case)]
 #[cfg(test)]
 mod tests {
+    use std::cmp;
+
+    use types::ternary::{U0, U1, U2, U3, U5};
     use super::*;
 
     #[test]
@@ -425,6 +1577,268 @@ mod tests {
         assert_eq!(<LteCmp<S0, SP2, SP2, SN2> as Int>::reify(), 2);
     }
 
+    #[test]
+    fn balanced_ternary_signum() {
+        assert_eq!(<Signum<S0> as Int>::reify(), 0);
+        assert_eq!(<Signum<SP1> as Int>::reify(), 1);
+        assert_eq!(<Signum<SN1> as Int>::reify(), -1);
+        assert_eq!(<Signum<SP5> as Int>::reify(), 1);
+        assert_eq!(<Signum<SN5> as Int>::reify(), -1);
+        assert_eq!(<Signum<SP26> as Int>::reify(), 1);
+        assert_eq!(<Signum<SN27> as Int>::reify(), -1);
+        assert_eq!(<Signum<Zero<Zero<Term>>> as Int>::reify(), 0);
+    }
+
+    #[test]
+    fn balanced_ternary_sign_predicates() {
+        assert!(<IsNegative<SN4> as Bool>::reify());
+        assert!(!<IsNegative<S0> as Bool>::reify());
+        assert!(!<IsNegative<SP2> as Bool>::reify());
+        assert!(!<IsPositive<SN4> as Bool>::reify());
+        assert!(!<IsPositive<S0> as Bool>::reify());
+        assert!(<IsPositive<SP2> as Bool>::reify());
+        assert!(!<IsZero<SN4> as Bool>::reify());
+        assert!(<IsZero<S0> as Bool>::reify());
+        assert!(!<IsZero<SP2> as Bool>::reify());
+    }
+
+    #[test]
+    fn balanced_ternary_is_even_odd() {
+        assert!(<IsEven<S0> as Bool>::reify());
+        assert!(!<IsEven<SP1> as Bool>::reify());
+        assert!(!<IsEven<SN1> as Bool>::reify());
+        assert!(<IsEven<SP2> as Bool>::reify());
+        assert!(<IsEven<SN8> as Bool>::reify());
+        assert!(!<IsEven<SN9> as Bool>::reify());
+        assert!(<IsOdd<SP5> as Bool>::reify());
+        assert!(!<IsOdd<SN6> as Bool>::reify());
+        assert!(<IsOdd<SN27> as Bool>::reify());
+    }
+
+    #[test]
+    fn balanced_ternary_compare() {
+        assert_eq!(<Compare<S0, S0> as Ordering>::reify(), cmp::Ordering::Equal);
+        assert_eq!(<Compare<SN7, SP9> as Ordering>::reify(), cmp::Ordering::Less);
+        assert_eq!(<Compare<SP9, SN7> as Ordering>::reify(), cmp::Ordering::Greater);
+        assert_eq!(<Compare<SN2, SN1> as Ordering>::reify(), cmp::Ordering::Less);
+        assert_eq!(<Compare<SP5, SP5> as Ordering>::reify(), cmp::Ordering::Equal);
+        assert_eq!(<Compare<S0, SN4> as Ordering>::reify(), cmp::Ordering::Greater);
+        assert_eq!(<Compare<SP13, SP14> as Ordering>::reify(), cmp::Ordering::Less);
+        assert_eq!(<Sign<SN4> as Ordering>::reify(), cmp::Ordering::Less);
+        assert_eq!(<Sign<S0> as Ordering>::reify(), cmp::Ordering::Equal);
+        assert_eq!(<Sign<SP27> as Ordering>::reify(), cmp::Ordering::Greater);
+        assert_eq!(<Match<Compare<SN3, SP4>, SN1, S0, SP1> as Int>::reify(), -1);
+        assert_eq!(<Match<Sign<SP2>, SN1, S0, SP1> as Int>::reify(), 1);
+    }
+
+    #[test]
+    fn balanced_ternary_shift() {
+        assert_eq!(<ShiftLeft<S0, U3> as Int>::reify(), 0);
+        assert_eq!(<ShiftLeft<SN5, U0> as Int>::reify(), -5);
+        assert_eq!(<ShiftLeft<SN5, U1> as Int>::reify(), -15);
+        assert_eq!(<ShiftLeft<SP2, U3> as Int>::reify(), 54);
+        assert_eq!(<ShiftRight<S0, U2> as Int>::reify(), 0);
+        assert_eq!(<ShiftRight<SP5, U0> as Int>::reify(), 5);
+        assert_eq!(<ShiftRight<SP5, U1> as Int>::reify(), 2);
+        assert_eq!(<ShiftRight<SP4, U1> as Int>::reify(), 1);
+        assert_eq!(<ShiftRight<SN5, U1> as Int>::reify(), -2);
+        assert_eq!(<ShiftRight<SP26, U2> as Int>::reify(), 3);
+        assert_eq!(<ShiftRight<SN13, U2> as Int>::reify(), -1);
+        assert_eq!(<ShiftRight<SP13, U3> as Int>::reify(), 0);
+        assert_eq!(<Third<SN8> as Int>::reify(), -3);
+    }
+
+    #[test]
+    fn balanced_ternary_low_high_trits() {
+        assert_eq!(<LowTrits<S0, U2> as Int>::reify(), 0);
+        assert_eq!(<LowTrits<SP17, U0> as Int>::reify(), 0);
+        assert_eq!(<LowTrits<SP17, U1> as Int>::reify(), -1);
+        assert_eq!(<LowTrits<SP17, U2> as Int>::reify(), -1);
+        assert_eq!(<LowTrits<SP17, U3> as Int>::reify(), -10);
+        assert_eq!(<LowTrits<SP17, U5> as Int>::reify(), 17);
+        assert_eq!(<LowTrits<SN13, U2> as Int>::reify(), -4);
+        assert_eq!(<HighTrits<SP17, U0> as Int>::reify(), 17);
+        assert_eq!(<HighTrits<SP17, U1> as Int>::reify(), 18);
+        assert_eq!(<HighTrits<SP17, U3> as Int>::reify(), 27);
+        assert_eq!(<HighTrits<SN13, U2> as Int>::reify(), -9);
+    }
+
+    #[test]
+    fn balanced_ternary_low_trits_canonical() {
+        let _: Term = <LowTrits<SP9, U2>>::default();
+        let _: Plus<Term> = <LowTrits<SP10, U2>>::default();
+        let _: Term = <HighTrits<SP4, U2>>::default();
+        let _: Zero<Zero<Minus<Term>>> = <ShiftLeft<SN1, U2>>::default();
+    }
+
+    #[test]
+    fn balanced_ternary_trit_len() {
+        assert_eq!(<TritLen<S0> as Nat>::reify(), 0);
+        assert_eq!(<TritLen<SP1> as Nat>::reify(), 1);
+        assert_eq!(<TritLen<SN1> as Nat>::reify(), 1);
+        assert_eq!(<TritLen<SP2> as Nat>::reify(), 2);
+        assert_eq!(<TritLen<SP4> as Nat>::reify(), 2);
+        assert_eq!(<TritLen<SP5> as Nat>::reify(), 3);
+        assert_eq!(<TritLen<SN27> as Nat>::reify(), 4);
+    }
+
+    #[test]
+    fn balanced_ternary_non_zero_trits() {
+        assert_eq!(<NonZeroTrits<S0> as Nat>::reify(), 0);
+        assert_eq!(<NonZeroTrits<SN1> as Nat>::reify(), 1);
+        assert_eq!(<NonZeroTrits<SP4> as Nat>::reify(), 2);
+        assert_eq!(<NonZeroTrits<SP5> as Nat>::reify(), 3);
+        assert_eq!(<NonZeroTrits<SN9> as Nat>::reify(), 1);
+        assert_eq!(<NonZeroTrits<SN26> as Nat>::reify(), 2);
+    }
+
+    #[test]
+    fn balanced_ternary_nat_errors() {
+        use strong::ternary;
+
+        let _: DivByZero = <ShiftLeft<SP2, ternary::Div<U1, U0>>>::default();
+        let _: Undefined = <ShiftLeft<SP2, ternary::Sub<U1, U2>>>::default();
+        let _: DivByZero = <ShiftRight<SP2, ternary::Rem<U1, U0>>>::default();
+        let _: Undefined = <ShiftRight<SP2, NatUndefined>>::default();
+        let _: DivByZero = <LowTrits<SN5, ternary::Div<U2, U0>>>::default();
+        let _: Undefined = <LowTrits<S0, ternary::Pred<U0>>>::default();
+        let _: NatDivByZero = <TritLen<Div<SP1, S0>>>::default();
+        let _: NatUndefined = <NonZeroTrits<Undefined>>::default();
+    }
+
+    #[test]
+    fn balanced_ternary_normalize() {
+        let _: Term = <Normalize<Zero<Zero<Term>>>>::default();
+        let _: Minus<Term> = <Normalize<Minus<Zero<Zero<Term>>>>>::default();
+        let _: Zero<Plus<Term>> = <Normalize<Zero<Plus<Zero<Term>>>>>::default();
+        let _: SN17 = <Normalize<SN17>>::default();
+        assert_eq!(<Normalize<Minus<Zero<Zero<Zero<Term>>>>> as Int>::reify(), -1);
+    }
+
+    #[test]
+    fn balanced_ternary_is_canonical() {
+        assert!(<IsCanonical<S0> as Bool>::reify());
+        assert!(<IsCanonical<SN9> as Bool>::reify());
+        assert!(<IsCanonical<SP26> as Bool>::reify());
+        assert!(!<IsCanonical<Zero<Term>> as Bool>::reify());
+        assert!(!<IsCanonical<Plus<Zero<Zero<Term>>>> as Bool>::reify());
+        assert!(!<IsCanonical<Undefined> as Bool>::reify());
+        assert!(!<IsCanonical<Minus<Undefined>> as Bool>::reify());
+    }
+
+    #[test]
+    fn balanced_ternary_canonical() {
+        fn canonical<N: Canonical>() -> isize {
+            N::reify()
+        }
+
+        assert_eq!(canonical::<S0>(), 0);
+        assert_eq!(canonical::<SN18>(), -18);
+        assert_eq!(canonical::<Normalize<Minus<Zero<Term>>>>(), -1);
+    }
+
+    #[test]
+    fn balanced_ternary_is_defined() {
+        assert!(<IsDefined<S0> as Bool>::reify());
+        assert!(<IsDefined<SN18> as Bool>::reify());
+        assert!(<IsDefined<Sub<SP3, SP5>> as Bool>::reify());
+        assert!(!<IsDefined<Div<SP3, S0>> as Bool>::reify());
+        assert!(!<IsDefined<Undefined> as Bool>::reify());
+        assert!(!<IsDefined<Minus<Undefined>> as Bool>::reify());
+    }
+
+    #[test]
+    fn balanced_ternary_unwrap_or() {
+        assert_eq!(<UnwrapOr<SN5, SP3> as Int>::reify(), -5);
+        assert_eq!(<UnwrapOr<Div<SN5, S0>, SP3> as Int>::reify(), 3);
+        assert_eq!(<UnwrapOr<Plus<Undefined>, SN1> as Int>::reify(), -1);
+    }
+
+    #[test]
+    fn balanced_ternary_defined() {
+        fn defined<N: Defined>() -> isize {
+            N::reify()
+        }
+
+        assert_eq!(defined::<S0>(), 0);
+        assert_eq!(defined::<Sub<SP3, SP5>>(), -2);
+        assert_eq!(defined::<DivFloor<SN5, SP2>>(), -3);
+    }
+
+    #[test]
+    fn balanced_ternary_trit_min_max() {
+        assert_eq!(<TritMin<SP5, SN7> as Int>::reify(), -13);
+        assert_eq!(<TritMin<SP13, SP4> as Int>::reify(), 4);
+        assert_eq!(<TritMin<SN9, SP2> as Int>::reify(), -10);
+        assert_eq!(<TritMin<S0, SN5> as Int>::reify(), -9);
+        assert_eq!(<TritMax<SP5, SN7> as Int>::reify(), 11);
+        assert_eq!(<TritMax<SN9, SP2> as Int>::reify(), 3);
+        assert_eq!(<TritMax<S0, SN5> as Int>::reify(), 4);
+        assert_eq!(<TritMax<SN13, SP3> as Int>::reify(), 3);
+        let _: SP8 = <TritMin<SP8, SP8>>::default();
+        let _: SP3 = <TritMax<SN13, SP3>>::default();
+    }
+
+    #[test]
+    fn balanced_ternary_trit_neg_mul_consensus() {
+        assert_eq!(<TritNeg<SP5> as Int>::reify(), -5);
+        assert_eq!(<TritMul<SP5, SN7> as Int>::reify(), -11);
+        assert_eq!(<TritMul<SP8, SP8> as Int>::reify(), 10);
+        assert_eq!(<TritMul<SN13, SP3> as Int>::reify(), -3);
+        assert_eq!(<TritConsensus<SP5, SN7> as Int>::reify(), -1);
+        assert_eq!(<TritConsensus<SP13, SP4> as Int>::reify(), 4);
+        assert_eq!(<TritConsensus<SP8, SP8> as Int>::reify(), 8);
+        let _: S0 = <TritMul<SN9, SP2>>::default();
+        let _: S0 = <TritMul<S0, SN5>>::default();
+        let _: S0 = <TritConsensus<SN13, SP3>>::default();
+    }
+
+    #[test]
+    fn balanced_ternary_min_max() {
+        assert_eq!(<Min<S0, S0> as Int>::reify(), 0);
+        assert_eq!(<Min<SN7, SP9> as Int>::reify(), -7);
+        assert_eq!(<Min<SP9, SN7> as Int>::reify(), -7);
+        assert_eq!(<Min<SN2, SN1> as Int>::reify(), -2);
+        assert_eq!(<Min<SP5, SP5> as Int>::reify(), 5);
+        assert_eq!(<Max<S0, S0> as Int>::reify(), 0);
+        assert_eq!(<Max<SN7, SP9> as Int>::reify(), 9);
+        assert_eq!(<Max<SP9, SN7> as Int>::reify(), 9);
+        assert_eq!(<Max<SN2, SN1> as Int>::reify(), -1);
+        assert_eq!(<Max<SP5, SP5> as Int>::reify(), 5);
+    }
+
+    #[test]
+    fn balanced_ternary_clamp() {
+        assert_eq!(<Clamp<SN9, SN3, SP4> as Int>::reify(), -3);
+        assert_eq!(<Clamp<SN3, SN3, SP4> as Int>::reify(), -3);
+        assert_eq!(<Clamp<S0, SN3, SP4> as Int>::reify(), 0);
+        assert_eq!(<Clamp<SP4, SN3, SP4> as Int>::reify(), 4);
+        assert_eq!(<Clamp<SP27, SN3, SP4> as Int>::reify(), 4);
+        assert_eq!(<Clamp<SP27, SN5, SN5> as Int>::reify(), -5);
+    }
+
+    #[test]
+    fn balanced_ternary_min_max_abs() {
+        assert_eq!(<MinAbs<SN7, SP9> as Int>::reify(), -7);
+        assert_eq!(<MinAbs<SP9, SN7> as Int>::reify(), -7);
+        assert_eq!(<MinAbs<SN2, SP2> as Int>::reify(), -2);
+        assert_eq!(<MinAbs<S0, SN1> as Int>::reify(), 0);
+        assert_eq!(<MaxAbs<SN7, SP5> as Int>::reify(), -7);
+        assert_eq!(<MaxAbs<SP5, SN7> as Int>::reify(), -7);
+        assert_eq!(<MaxAbs<SP2, SN2> as Int>::reify(), 2);
+        assert_eq!(<MaxAbs<S0, SN1> as Int>::reify(), -1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn balanced_ternary_min_max_undefined() {
+        let _: Undefined = <Min<Undefined, SP3>>::default();
+        let _: Undefined = <Max<SP3, Undefined>>::default();
+        let _: Undefined = <MinAbs<SP3, Undefined>>::default();
+        let _: Undefined = <Clamp<SP3, Undefined, SP5>>::default();
+        let _ = <Clamp<SP3, SP5, SP4> as Int>::reify();
+    }
+
     #[test]
     fn balanced_ternary_abs_min_three_cmp() {
         assert_eq!(<AbsMinThreeCmp<SP5, SN7, SN8, S0, SP1, SP2> as IntPair>::reify(), (5, 0));
@@ -508,6 +1922,8 @@ mod tests {
         assert_eq!(<Mul<SP5, SN8> as Int>::reify(), -40);
         assert_eq!(<Mul<SN8, SP2> as Int>::reify(), -16);
         assert_eq!(<Mul<SP3, SP2> as Int>::reify(), 6);
+        assert_eq!(<Mul<SP6, SP4> as Int>::reify(), 24);
+        assert_eq!(<Mul<SN12, SP7> as Int>::reify(), -84);
     }
 
     #[test]
@@ -534,6 +1950,208 @@ mod tests {
         assert_eq!(<Rem<SP3, SP2> as Int>::reify(), 1);
     }
 
+    #[test]
+    fn balanced_ternary_div_rem() {
+        assert_eq!(<DivRem<S0, SP1> as IntPair>::reify(), (0, 0));
+        assert_eq!(<DivRem<SN8, SP2> as IntPair>::reify(), (-4, 0));
+        assert_eq!(<DivRem<SN7, SP9> as IntPair>::reify(), (-1, 2));
+        assert_eq!(<DivRem<SN9, SP5> as IntPair>::reify(), (-2, 1));
+        assert_eq!(<DivRem<SP5, SN8> as IntPair>::reify(), (-1, -3));
+        assert_eq!(<Int2First<DivRem<SP17, SP3>> as Int>::reify(), 6);
+        assert_eq!(<Int2Second<DivRem<SP17, SP3>> as Int>::reify(), -1);
+        assert_eq!(<Int2Swap<DivRem<SP17, SP3>> as IntPair>::reify(), (-1, 6));
+    }
+
+    #[test]
+    fn balanced_ternary_div_rem_trunc() {
+        assert_eq!(<DivTrunc<SP7, SP2> as Int>::reify(), 7isize / 2);
+        assert_eq!(<RemTrunc<SP7, SP2> as Int>::reify(), 7isize % 2);
+        assert_eq!(<DivTrunc<SN7, SP2> as Int>::reify(), (-7isize) / 2);
+        assert_eq!(<RemTrunc<SN7, SP2> as Int>::reify(), (-7isize) % 2);
+        assert_eq!(<DivTrunc<SP7, SN2> as Int>::reify(), 7isize / (-2));
+        assert_eq!(<RemTrunc<SP7, SN2> as Int>::reify(), 7isize % (-2));
+        assert_eq!(<DivTrunc<SN7, SN2> as Int>::reify(), (-7isize) / (-2));
+        assert_eq!(<RemTrunc<SN7, SN2> as Int>::reify(), (-7isize) % (-2));
+        assert_eq!(<DivTrunc<SP9, SP3> as Int>::reify(), 9isize / 3);
+        assert_eq!(<RemTrunc<SP9, SP3> as Int>::reify(), 9isize % 3);
+        assert_eq!(<DivTrunc<SN9, SP3> as Int>::reify(), (-9isize) / 3);
+        assert_eq!(<RemTrunc<SN9, SP3> as Int>::reify(), (-9isize) % 3);
+        assert_eq!(<DivTrunc<SP5, SN8> as Int>::reify(), 5isize / (-8));
+        assert_eq!(<RemTrunc<SP5, SN8> as Int>::reify(), 5);
+        assert_eq!(<DivTrunc<S0, SP5> as Int>::reify(), 0);
+        assert_eq!(<RemTrunc<S0, SP5> as Int>::reify(), 0);
+        assert_eq!(<DivTrunc<SP8, SP3> as Int>::reify(), 8isize / 3);
+        assert_eq!(<RemTrunc<SP8, SP3> as Int>::reify(), 8isize % 3);
+        assert_eq!(<DivTrunc<SN8, SN3> as Int>::reify(), (-8isize) / (-3));
+        assert_eq!(<RemTrunc<SN8, SN3> as Int>::reify(), (-8isize) % (-3));
+        assert_eq!(<DivTrunc<SP13, SP4> as Int>::reify(), 13isize / 4);
+        assert_eq!(<RemTrunc<SP13, SP4> as Int>::reify(), 13isize % 4);
+        assert_eq!(<DivTrunc<SN13, SP4> as Int>::reify(), (-13isize) / 4);
+        assert_eq!(<RemTrunc<SN13, SP4> as Int>::reify(), (-13isize) % 4);
+    }
+
+    #[test]
+    fn balanced_ternary_div_rem_euclid() {
+        assert_eq!(<DivEuclid<SP7, SP2> as Int>::reify(), 7isize.div_euclid(2));
+        assert_eq!(<RemEuclid<SP7, SP2> as Int>::reify(), 7isize.rem_euclid(2));
+        assert_eq!(<DivEuclid<SN7, SP2> as Int>::reify(), (-7isize).div_euclid(2));
+        assert_eq!(<RemEuclid<SN7, SP2> as Int>::reify(), (-7isize).rem_euclid(2));
+        assert_eq!(<DivEuclid<SP7, SN2> as Int>::reify(), 7isize.div_euclid(-2));
+        assert_eq!(<RemEuclid<SP7, SN2> as Int>::reify(), 7isize.rem_euclid(-2));
+        assert_eq!(<DivEuclid<SN7, SN2> as Int>::reify(), (-7isize).div_euclid(-2));
+        assert_eq!(<RemEuclid<SN7, SN2> as Int>::reify(), (-7isize).rem_euclid(-2));
+        assert_eq!(<DivEuclid<SP9, SP3> as Int>::reify(), 9isize.div_euclid(3));
+        assert_eq!(<RemEuclid<SP9, SP3> as Int>::reify(), 9isize.rem_euclid(3));
+        assert_eq!(<DivEuclid<SN9, SP3> as Int>::reify(), (-9isize).div_euclid(3));
+        assert_eq!(<RemEuclid<SN9, SP3> as Int>::reify(), (-9isize).rem_euclid(3));
+        assert_eq!(<DivEuclid<SP5, SN8> as Int>::reify(), 5isize.div_euclid(-8));
+        assert_eq!(<RemEuclid<SP5, SN8> as Int>::reify(), 5isize.rem_euclid(-8));
+        assert_eq!(<DivEuclid<S0, SP5> as Int>::reify(), 0isize.div_euclid(5));
+        assert_eq!(<RemEuclid<S0, SP5> as Int>::reify(), 0isize.rem_euclid(5));
+        assert_eq!(<DivEuclid<SP8, SP3> as Int>::reify(), 8isize.div_euclid(3));
+        assert_eq!(<RemEuclid<SP8, SP3> as Int>::reify(), 8isize.rem_euclid(3));
+        assert_eq!(<DivEuclid<SN8, SN3> as Int>::reify(), (-8isize).div_euclid(-3));
+        assert_eq!(<RemEuclid<SN8, SN3> as Int>::reify(), (-8isize).rem_euclid(-3));
+        assert_eq!(<DivEuclid<SP13, SP4> as Int>::reify(), 13isize.div_euclid(4));
+        assert_eq!(<RemEuclid<SP13, SP4> as Int>::reify(), 13isize.rem_euclid(4));
+        assert_eq!(<DivEuclid<SN13, SP4> as Int>::reify(), (-13isize).div_euclid(4));
+        assert_eq!(<RemEuclid<SN13, SP4> as Int>::reify(), (-13isize).rem_euclid(4));
+    }
+
+    #[test]
+    fn balanced_ternary_div_rem_floor() {
+        assert_eq!(<DivFloor<SP7, SP2> as Int>::reify(), 3);
+        assert_eq!(<RemFloor<SP7, SP2> as Int>::reify(), 1);
+        assert_eq!(<DivFloor<SN7, SP2> as Int>::reify(), -4);
+        assert_eq!(<RemFloor<SN7, SP2> as Int>::reify(), 1);
+        assert_eq!(<DivFloor<SP7, SN2> as Int>::reify(), -4);
+        assert_eq!(<RemFloor<SP7, SN2> as Int>::reify(), -1);
+        assert_eq!(<DivFloor<SN7, SN2> as Int>::reify(), 3);
+        assert_eq!(<RemFloor<SN7, SN2> as Int>::reify(), -1);
+        assert_eq!(<DivFloor<SP9, SP3> as Int>::reify(), 3);
+        assert_eq!(<RemFloor<SP9, SP3> as Int>::reify(), 0);
+        assert_eq!(<DivFloor<SN9, SP3> as Int>::reify(), -3);
+        assert_eq!(<RemFloor<SN9, SP3> as Int>::reify(), 0);
+        assert_eq!(<DivFloor<SP5, SN8> as Int>::reify(), -1);
+        assert_eq!(<RemFloor<SP5, SN8> as Int>::reify(), -3);
+        assert_eq!(<DivFloor<S0, SP5> as Int>::reify(), 0);
+        assert_eq!(<RemFloor<S0, SP5> as Int>::reify(), 0);
+        assert_eq!(<DivFloor<SP8, SP3> as Int>::reify(), 2);
+        assert_eq!(<RemFloor<SP8, SP3> as Int>::reify(), 2);
+        assert_eq!(<DivFloor<SN8, SN3> as Int>::reify(), 2);
+        assert_eq!(<RemFloor<SN8, SN3> as Int>::reify(), -2);
+        assert_eq!(<DivFloor<SP13, SP4> as Int>::reify(), 3);
+        assert_eq!(<RemFloor<SP13, SP4> as Int>::reify(), 1);
+        assert_eq!(<DivFloor<SN13, SP4> as Int>::reify(), -4);
+        assert_eq!(<RemFloor<SN13, SP4> as Int>::reify(), 3);
+    }
+
+    #[test]
+    fn balanced_ternary_div_rem_ceil() {
+        assert_eq!(<DivCeil<SP7, SP2> as Int>::reify(), 4);
+        assert_eq!(<RemCeil<SP7, SP2> as Int>::reify(), -1);
+        assert_eq!(<DivCeil<SN7, SP2> as Int>::reify(), -3);
+        assert_eq!(<RemCeil<SN7, SP2> as Int>::reify(), -1);
+        assert_eq!(<DivCeil<SP7, SN2> as Int>::reify(), -3);
+        assert_eq!(<RemCeil<SP7, SN2> as Int>::reify(), 1);
+        assert_eq!(<DivCeil<SN7, SN2> as Int>::reify(), 4);
+        assert_eq!(<RemCeil<SN7, SN2> as Int>::reify(), 1);
+        assert_eq!(<DivCeil<SP9, SP3> as Int>::reify(), 3);
+        assert_eq!(<RemCeil<SP9, SP3> as Int>::reify(), 0);
+        assert_eq!(<DivCeil<SN9, SP3> as Int>::reify(), -3);
+        assert_eq!(<RemCeil<SN9, SP3> as Int>::reify(), 0);
+        assert_eq!(<DivCeil<SP5, SN8> as Int>::reify(), 0);
+        assert_eq!(<RemCeil<SP5, SN8> as Int>::reify(), 5);
+        assert_eq!(<DivCeil<S0, SP5> as Int>::reify(), 0);
+        assert_eq!(<RemCeil<S0, SP5> as Int>::reify(), 0);
+        assert_eq!(<DivCeil<SP8, SP3> as Int>::reify(), 3);
+        assert_eq!(<RemCeil<SP8, SP3> as Int>::reify(), -1);
+        assert_eq!(<DivCeil<SN8, SN3> as Int>::reify(), 3);
+        assert_eq!(<RemCeil<SN8, SN3> as Int>::reify(), 1);
+        assert_eq!(<DivCeil<SP13, SP4> as Int>::reify(), 4);
+        assert_eq!(<RemCeil<SP13, SP4> as Int>::reify(), -3);
+        assert_eq!(<DivCeil<SN13, SP4> as Int>::reify(), -3);
+        assert_eq!(<RemCeil<SN13, SP4> as Int>::reify(), -1);
+    }
+
+    #[test]
+    fn balanced_ternary_div_rem_round() {
+        assert_eq!(<DivRound<SP8, SP3> as Int>::reify(), 3);
+        assert_eq!(<RemRound<SP8, SP3> as Int>::reify(), -1);
+        assert_eq!(<DivRound<SN8, SP3> as Int>::reify(), -3);
+        assert_eq!(<RemRound<SN8, SP3> as Int>::reify(), 1);
+        assert_eq!(<DivRound<SP13, SN4> as Int>::reify(), -3);
+        assert_eq!(<RemRound<SP13, SN4> as Int>::reify(), 1);
+        assert_eq!(<DivRound<SN9, SP5> as Int>::reify(), -2);
+        assert_eq!(<RemRound<SN9, SP5> as Int>::reify(), 1);
+    }
+
+    // Check `DivRem` and every rounding mode of `N / D` against `isize` arithmetic.
+    fn check_division<N, D>()
+        where N: IntDivRem<D> + IntDiv<D> + IntRem<D> + IntDivRound<D> + IntRemRound<D> +
+                 IntDivTrunc<D> + IntRemTrunc<D> + IntDivFloor<D> + IntRemFloor<D> +
+                 IntDivCeil<D> + IntRemCeil<D> + IntDivEuclid<D> + IntRemEuclid<D>,
+              D: Int
+    {
+        let (n, d) = (N::reify(), D::reify());
+        let (q, r) = (<DivRound<N, D> as Int>::reify(), <RemRound<N, D> as Int>::reify());
+        assert_eq!(q * d + r, n, "DivRound<{}, {}>", n, d);
+        assert!(2 * r.abs() <= d.abs(), "RemRound<{}, {}> = {}", n, d, r);
+        assert_eq!(<DivRem<N, D> as IntPair>::reify(), (q, r), "DivRem<{}, {}>", n, d);
+        assert_eq!(<Div<N, D> as Int>::reify(), q, "Div<{}, {}>", n, d);
+        assert_eq!(<Rem<N, D> as Int>::reify(), r, "Rem<{}, {}>", n, d);
+
+        let floor = if n % d != 0 && (n < 0) != (d < 0) { n / d - 1 } else { n / d };
+        let ceil = if n % d != 0 && (n < 0) == (d < 0) { n / d + 1 } else { n / d };
+        assert_eq!(<DivTrunc<N, D> as Int>::reify(), n / d, "DivTrunc<{}, {}>", n, d);
+        assert_eq!(<RemTrunc<N, D> as Int>::reify(), n % d, "RemTrunc<{}, {}>", n, d);
+        assert_eq!(<DivFloor<N, D> as Int>::reify(), floor, "DivFloor<{}, {}>", n, d);
+        assert_eq!(<RemFloor<N, D> as Int>::reify(), n - floor * d, "RemFloor<{}, {}>", n, d);
+        assert_eq!(<DivCeil<N, D> as Int>::reify(), ceil, "DivCeil<{}, {}>", n, d);
+        assert_eq!(<RemCeil<N, D> as Int>::reify(), n - ceil * d, "RemCeil<{}, {}>", n, d);
+        assert_eq!(<DivEuclid<N, D> as Int>::reify(), n.div_euclid(d), "DivEuclid<{}, {}>", n, d);
+        assert_eq!(<RemEuclid<N, D> as Int>::reify(), n.rem_euclid(d), "RemEuclid<{}, {}>", n, d);
+    }
+
+    // Call `$check::<N, D>()` for every `N` in the first list and `D` in the second.
+    macro_rules! for_all_pairs {
+        ($check:ident; [$($n:ident),*]; $ds:tt) => {
+            $(for_all_pairs!(@divisors $check; $n; $ds);)*
+        };
+        (@divisors $check:ident; $n:ident; [$($d:ident),*]) => {
+            $($check::<$n, $d>();)*
+        };
+    }
+
+    #[test]
+    fn balanced_ternary_div_rem_exhaustive() {
+        for_all_pairs!(check_division;
+            [SN27, SN26, SN25, SN24, SN23, SN22, SN21, SN20, SN19, SN18, SN17, SN16, SN15, SN14,
+             SN13, SN12, SN11, SN10, SN9, SN8, SN7, SN6, SN5, SN4, SN3, SN2, SN1, S0, SP1, SP2,
+             SP3, SP4, SP5, SP6, SP7, SP8, SP9, SP10, SP11, SP12, SP13, SP14, SP15, SP16, SP17,
+             SP18, SP19, SP20, SP21, SP22, SP23, SP24, SP25, SP26, SP27];
+            [SN10, SN9, SN8, SN7, SN6, SN5, SN4, SN3, SN2, SN1, SP1, SP2, SP3, SP4, SP5, SP6, SP7,
+             SP8, SP9, SP10]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn balanced_ternary_div_undefined() {
+        let _: DivByZero = <Div<SP5, S0>>::default();
+        let _: Undefined = <Rem<Undefined, SP5>>::default();
+        let _: DivByZero = <DivFloor<SN5, S0>>::default();
+        let _: Undefined = <RemEuclid<SP5, Undefined>>::default();
+        let _: DivByZero = <DivTrunc<S0, S0>>::default();
+        let _: DivByZero = <Mul<Div<SP3, S0>, Undefined>>::default();
+        let _ = <DivCeil<SP5, S0> as Int>::reify();
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn balanced_ternary_div_by_zero_message() {
+        let _ = <RemFloor<SN5, S0> as Int>::reify();
+    }
+
     #[test]
     fn balanced_ternary_rev() {
         assert_eq!(<Rev<SN9> as Int>::reify(), -1);
@@ -549,5 +2167,7 @@ mod tests {
         assert_eq!(<Rev<SP1> as Int>::reify(), 1);
         assert_eq!(<Rev<SP2> as Int>::reify(), -2);
         assert_eq!(<Rev<SP3> as Int>::reify(), 1);
+        let _: SP1 = <Rev<SP3>>::default();
+        let _: SN1 = <Rev<SN9>>::default();
     }
 }
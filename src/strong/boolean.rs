@@ -1,49 +1,117 @@
-//! Type-level boolean operations.
+//! Type-level boolean operations. Each operator is undefined if any of its arguments is.
 
 pub use types::boolean::*;
 
 type_operators! {
     [A, B, C, D, E]
 
+    /// Boolean `Not`. Use as `Not<X>` or `<X as BoolNot>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Not<{Self}>`",
+        label = "`Not` is not implemented for these arguments",
+        note = "`Not` takes a `Bool`",
+        note = "in generic code, add `{Self}: BoolNot` to the `where` clause"
+    )]
+    (Not) BoolNot(Bool): Bool {
+        [False] => True
+        [True] => False
+        [Undefined] => Undefined
+    }
+
     /// Boolean `And`. Use as `And<X, Y>` or `<X as BoolAnd<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `And<{Self}, {B}>`",
+        label = "`And` is not implemented for these arguments",
+        note = "`And` takes two `Bool`s",
+        note = "in generic code, add `{Self}: BoolAnd<{B}>` to the `where` clause"
+    )]
     (And) BoolAnd(Bool, Bool): Bool {
         [False, False] => False
         [False, True] => False
         [True, False] => False
         [True, True] => True
+        [False, Undefined] => Undefined
+        [True, Undefined] => Undefined
+        [Undefined, False] => Undefined
+        [Undefined, True] => Undefined
+        [Undefined, Undefined] => Undefined
     }
 
     /// Boolean `Or`. Use as `Or<X, Y>` or `<X as BoolOr<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Or<{Self}, {B}>`",
+        label = "`Or` is not implemented for these arguments",
+        note = "`Or` takes two `Bool`s",
+        note = "in generic code, add `{Self}: BoolOr<{B}>` to the `where` clause"
+    )]
     (Or) BoolOr(Bool, Bool): Bool {
         [False, False] => False
         [False, True] => True
         [True, False] => True
         [True, True] => True
+        [False, Undefined] => Undefined
+        [True, Undefined] => Undefined
+        [Undefined, False] => Undefined
+        [Undefined, True] => Undefined
+        [Undefined, Undefined] => Undefined
     }
 
     /// Boolean `IfThen`. Use as `IfThen<X, Y>` or `<X as BoolIfThen<Y>>::Output`. As a logical
     /// proposition, this is equivalent to "**if** `X`, **then** `Y`".
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IfThen<{Self}, {B}>`",
+        label = "`IfThen` is not implemented for these arguments",
+        note = "`IfThen` takes two `Bool`s",
+        note = "in generic code, add `{Self}: BoolIfThen<{B}>` to the `where` clause"
+    )]
     (IfThen) BoolIfThen(Bool, Bool): Bool {
         [False, False] => True
         [False, True] => True
         [True, False] => False
         [True, True] => True
+        [False, Undefined] => Undefined
+        [True, Undefined] => Undefined
+        [Undefined, False] => Undefined
+        [Undefined, True] => Undefined
+        [Undefined, Undefined] => Undefined
     }
 
     /// Boolean `OnlyIf`. Use as `OnlyIf<X, Y>` or `<X as BoolOnlyIf<Y>>::Output`. As a logical
     /// proposition, this is equivalent to "`Y` **only if** `X`".
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `OnlyIf<{Self}, {B}>`",
+        label = "`OnlyIf` is not implemented for these arguments",
+        note = "`OnlyIf` takes two `Bool`s",
+        note = "in generic code, add `{Self}: BoolOnlyIf<{B}>` to the `where` clause"
+    )]
     (OnlyIf) BoolOnlyIf(Bool, Bool): Bool {
         [False, False] => True
         [False, True] => False
         [True, False] => True
         [True, True] => True
+        [False, Undefined] => Undefined
+        [True, Undefined] => Undefined
+        [Undefined, False] => Undefined
+        [Undefined, True] => Undefined
+        [Undefined, Undefined] => Undefined
     }
 
     /// Boolean `Xor`. Use as `Xor<X, Y>` or `<X as BoolXor<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Xor<{Self}, {B}>`",
+        label = "`Xor` is not implemented for these arguments",
+        note = "`Xor` takes two `Bool`s",
+        note = "in generic code, add `{Self}: BoolXor<{B}>` to the `where` clause"
+    )]
     (Xor) BoolXor(Bool, Bool): Bool {
         [False, False] => False
         [False, True] => True
         [True, False] => True
         [True, True] => False
+        [False, Undefined] => Undefined
+        [True, Undefined] => Undefined
+        [Undefined, False] => Undefined
+        [Undefined, True] => Undefined
+        [Undefined, Undefined] => Undefined
     }
 }
@@ -0,0 +1,311 @@
+//! Type-level arithmetic on fixed-width ternary words. Both operands of an operator must have
+//! the same width. Results wrap around modulo `3^W`, like the arithmetic of a `W`-trit register,
+//! and come paired with a `Bool` recording whether anything was lost; use `UValue`/`UCarry` and
+//! `IValue`/`IOverflow` to take them apart.
+
+pub use types::fixed::*;
+
+use strong::boolean::{Bool, BoolNot, True, False};
+use strong::ternary::{Nat, NatAdd, NatSub, NatMul, NatCmp, NatShiftLeft, NatShiftRight,
+                      NatLowTrits, Term as NatTerm, Zero as NatZero, One as NatOne,
+                      Two as NatTwo};
+use strong::balanced::{Int, IntAdd, IntSub, IntMul, IntShiftRight, IntLowTrits, IntIsZero};
+
+type_operators! {
+    [A, B, C, D, E]
+
+    /// Unsigned word addition. Used as `UAdd<X, Y>` or `<X as UWordAdd<Y>>::Output`. The carry
+    /// is `True` if the sum is at least `3^W`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `UAdd<{Self}, {B}>`",
+        label = "`UAdd` is not implemented for these arguments",
+        note = "`UAdd` takes two `UWord`s",
+        note = "in generic code, add `{Self}: UWordAdd<{B}>` to the `where` clause"
+    )]
+    (UAdd) UWordAdd(UWord, UWord): UWordResult {
+        forall (W: Nat, X: Nat, Y: Nat) {
+            [(UFix W X), (UFix W Y)] => (@UWordSplit W (@NatAdd X Y))
+        }
+    }
+
+    /// Unsigned word subtraction. Used as `USub<X, Y>` or `<X as UWordSub<Y>>::Output`. The carry
+    /// is `True` if `Y` is greater than `X`, in which case the result has wrapped around.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `USub<{Self}, {B}>`",
+        label = "`USub` is not implemented for these arguments",
+        note = "`USub` takes two `UWord`s",
+        note = "in generic code, add `{Self}: UWordSub<{B}>` to the `where` clause"
+    )]
+    (USub) UWordSub(UWord, UWord): UWordResult {
+        forall (W: Nat, X: Nat, Y: Nat) {
+            [(UFix W X), (UFix W Y)] => (UResult
+                (UFix W (@NatLowTrits (@NatSub (@NatAdd X (@NatShiftLeft NatOne W)) Y) W))
+                (@NatNonZero (@NatCmp X Y NatOne NatTerm NatTerm)))
+        }
+    }
+
+    /// Unsigned word multiplication. Used as `UMul<X, Y>` or `<X as UWordMul<Y>>::Output`. The
+    /// carry is `True` if the product is at least `3^W`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `UMul<{Self}, {B}>`",
+        label = "`UMul` is not implemented for these arguments",
+        note = "`UMul` takes two `UWord`s",
+        note = "in generic code, add `{Self}: UWordMul<{B}>` to the `where` clause"
+    )]
+    (UMul) UWordMul(UWord, UWord): UWordResult {
+        forall (W: Nat, X: Nat, Y: Nat) {
+            [(UFix W X), (UFix W Y)] => (@UWordSplit W (@NatMul X Y))
+        }
+    }
+
+    /// Split an exact result into its lowest `W` trits and a carry flag for the rest.
+    (USplit) UWordSplit(Nat, Nat): UWordResult {
+        forall (W: Nat, N: Nat) {
+            [W, N] => (UResult (UFix W (@NatLowTrits N W)) (@NatNonZero (@NatShiftRight N W)))
+        }
+    }
+
+    (NonZero) NatNonZero(Nat): Bool {
+        [NatTerm] => False
+        forall (N: Nat) {
+            [(NatZero N)] => (# N)
+            [(NatOne N)] => True
+            [(NatTwo N)] => True
+        }
+    }
+
+    /// The wrapped word in a `UWordResult`. Used as `UValue<R>` or
+    /// `<R as UWordResultValue>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `UValue<{Self}>`",
+        label = "`UValue` is not implemented for these arguments",
+        note = "`UValue` takes an `UWordResult`",
+        note = "in generic code, add `{Self}: UWordResultValue` to the `where` clause"
+    )]
+    (UValue) UWordResultValue(UWordResult): UWord {
+        forall (X: UWord, C: Bool) {
+            [(UResult X C)] => X
+        }
+    }
+
+    /// The carry flag in a `UWordResult`. Used as `UCarry<R>` or
+    /// `<R as UWordResultCarry>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `UCarry<{Self}>`",
+        label = "`UCarry` is not implemented for these arguments",
+        note = "`UCarry` takes an `UWordResult`",
+        note = "in generic code, add `{Self}: UWordResultCarry` to the `where` clause"
+    )]
+    (UCarry) UWordResultCarry(UWordResult): Bool {
+        forall (X: UWord, C: Bool) {
+            [(UResult X C)] => C
+        }
+    }
+
+    /// Balanced word addition. Used as `IAdd<X, Y>` or `<X as IWordAdd<Y>>::Output`. The overflow
+    /// flag is `True` if the exact sum does not fit in `W` trits.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IAdd<{Self}, {B}>`",
+        label = "`IAdd` is not implemented for these arguments",
+        note = "`IAdd` takes two `IWord`s",
+        note = "in generic code, add `{Self}: IWordAdd<{B}>` to the `where` clause"
+    )]
+    (IAdd) IWordAdd(IWord, IWord): IWordResult {
+        forall (W: Nat, X: Int, Y: Int) {
+            [(IFix W X), (IFix W Y)] => (@IWordSplit W (@IntAdd X Y))
+        }
+    }
+
+    /// Balanced word subtraction. Used as `ISub<X, Y>` or `<X as IWordSub<Y>>::Output`. The
+    /// overflow flag is `True` if the exact difference does not fit in `W` trits.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `ISub<{Self}, {B}>`",
+        label = "`ISub` is not implemented for these arguments",
+        note = "`ISub` takes two `IWord`s",
+        note = "in generic code, add `{Self}: IWordSub<{B}>` to the `where` clause"
+    )]
+    (ISub) IWordSub(IWord, IWord): IWordResult {
+        forall (W: Nat, X: Int, Y: Int) {
+            [(IFix W X), (IFix W Y)] => (@IWordSplit W (@IntSub X Y))
+        }
+    }
+
+    /// Balanced word multiplication. Used as `IMul<X, Y>` or `<X as IWordMul<Y>>::Output`. The
+    /// overflow flag is `True` if the exact product does not fit in `W` trits.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IMul<{Self}, {B}>`",
+        label = "`IMul` is not implemented for these arguments",
+        note = "`IMul` takes two `IWord`s",
+        note = "in generic code, add `{Self}: IWordMul<{B}>` to the `where` clause"
+    )]
+    (IMul) IWordMul(IWord, IWord): IWordResult {
+        forall (W: Nat, X: Int, Y: Int) {
+            [(IFix W X), (IFix W Y)] => (@IWordSplit W (@IntMul X Y))
+        }
+    }
+
+    /// Split an exact result into its lowest `W` balanced trits and an overflow flag for the
+    /// rest. In balanced ternary the low trits are already the correctly wrapped value, with no
+    /// adjustment needed for the sign.
+    (ISplit) IWordSplit(Nat, Int): IWordResult {
+        forall (W: Nat, N: Int) {
+            [W, N] => (IResult
+                (IFix W (@IntLowTrits N W))
+                (@BoolNot (@IntIsZero (@IntShiftRight N W))))
+        }
+    }
+
+    /// The wrapped word in an `IWordResult`. Used as `IValue<R>` or
+    /// `<R as IWordResultValue>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IValue<{Self}>`",
+        label = "`IValue` is not implemented for these arguments",
+        note = "`IValue` takes an `IWordResult`",
+        note = "in generic code, add `{Self}: IWordResultValue` to the `where` clause"
+    )]
+    (IValue) IWordResultValue(IWordResult): IWord {
+        forall (X: IWord, O: Bool) {
+            [(IResult X O)] => X
+        }
+    }
+
+    /// The overflow flag in an `IWordResult`. Used as `IOverflow<R>` or
+    /// `<R as IWordResultOverflow>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IOverflow<{Self}>`",
+        label = "`IOverflow` is not implemented for these arguments",
+        note = "`IOverflow` takes an `IWordResult`",
+        note = "in generic code, add `{Self}: IWordResultOverflow` to the `where` clause"
+    )]
+    (IOverflow) IWordResultOverflow(IWordResult): Bool {
+        forall (X: IWord, O: Bool) {
+            [(IResult X O)] => O
+        }
+    }
+
+    /// Truncate a `Nat` to a `W`-trit unsigned word, keeping its lowest `W` trits like an `as`
+    /// cast. Used as `FromNat<N, W>` or `<N as NatToUWord<W>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `FromNat<{Self}, {B}>`",
+        label = "`FromNat` is not implemented for these arguments",
+        note = "`FromNat` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatToUWord<{B}>` to the `where` clause"
+    )]
+    (FromNat) NatToUWord(Nat, Nat): UWord {
+        forall (N: Nat, W: Nat) {
+            [N, W] => (UFix W (@NatLowTrits N W))
+        }
+    }
+
+    /// The value of an unsigned word, as a `Nat`. Used as `ToNat<X>` or
+    /// `<X as UWordToNat>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `ToNat<{Self}>`",
+        label = "`ToNat` is not implemented for these arguments",
+        note = "`ToNat` takes an `UWord`",
+        note = "in generic code, add `{Self}: UWordToNat` to the `where` clause"
+    )]
+    (ToNat) UWordToNat(UWord): Nat {
+        forall (W: Nat, N: Nat) {
+            [(UFix W N)] => N
+        }
+    }
+
+    /// Truncate an `Int` to a `W`-trit balanced word, keeping its lowest `W` balanced trits. Used
+    /// as `FromInt<N, W>` or `<N as IntToIWord<W>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `FromInt<{Self}, {B}>`",
+        label = "`FromInt` is not implemented for these arguments",
+        note = "`FromInt` takes an `Int` and a `Nat`",
+        note = "in generic code, add `{Self}: IntToIWord<{B}>` to the `where` clause"
+    )]
+    (FromInt) IntToIWord(Int, Nat): IWord {
+        forall (N: Int, W: Nat) {
+            [N, W] => (IFix W (@IntLowTrits N W))
+        }
+    }
+
+    /// The value of a balanced word, as an `Int`. Used as `ToInt<X>` or
+    /// `<X as IWordToInt>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `ToInt<{Self}>`",
+        label = "`ToInt` is not implemented for these arguments",
+        note = "`ToInt` takes an `IWord`",
+        note = "in generic code, add `{Self}: IWordToInt` to the `where` clause"
+    )]
+    (ToInt) IWordToInt(IWord): Int {
+        forall (W: Nat, I: Int) {
+            [(IFix W I)] => I
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use strong::balanced::{SN243, SN81, SN9, SN5, SN2, S0, SP2, SP3, SP5, SP9, SP81, SP243};
+    use strong::ternary::{U0, U1, U2, U3, U5, U6, U9, U26, U27, U80, U81, U243};
+    use super::*;
+
+    type U728 = NatTwo<NatTwo<NatTwo<NatTwo<NatTwo<NatTwo<NatTerm>>>>>>;
+
+    #[test]
+    fn fixed_unsigned_add() {
+        assert_eq!(<UAdd<UTryte<U243>, UTryte<U81>> as UWordResult>::reify(), (324, false));
+        assert_eq!(<UAdd<UTryte<U728>, UTryte<U1>> as UWordResult>::reify(), (0, true));
+        assert_eq!(<UAdd<UTryte<U728>, UTryte<U728>> as UWordResult>::reify(), (727, true));
+        assert_eq!(<UAdd<UFix<U2, U5>, UFix<U2, U5>> as UWordResult>::reify(), (1, true));
+        assert_eq!(<UAdd<UFix<U0, U0>, UFix<U0, U0>> as UWordResult>::reify(), (0, false));
+        assert_eq!(<ToNat<UValue<UAdd<UTryte<U26>, UTryte<U1>>>> as Nat>::reify(), 27);
+        assert!(!<UCarry<UAdd<UTryte<U26>, UTryte<U1>>> as Bool>::reify());
+    }
+
+    #[test]
+    fn fixed_unsigned_sub() {
+        assert_eq!(<USub<UTryte<U243>, UTryte<U81>> as UWordResult>::reify(), (162, false));
+        assert_eq!(<USub<UTryte<U5>, UTryte<U5>> as UWordResult>::reify(), (0, false));
+        assert_eq!(<USub<UTryte<U0>, UTryte<U1>> as UWordResult>::reify(), (728, true));
+        assert_eq!(<USub<UFix<U2, U3>, UFix<U2, U5>> as UWordResult>::reify(), (7, true));
+    }
+
+    #[test]
+    fn fixed_unsigned_mul() {
+        assert_eq!(<UMul<UTryte<U27>, UTryte<U9>> as UWordResult>::reify(), (243, false));
+        assert_eq!(<UMul<UTryte<U81>, UTryte<U9>> as UWordResult>::reify(), (0, true));
+        assert_eq!(<UMul<UTryte<U80>, UTryte<U27>> as UWordResult>::reify(),
+                   (80 * 27 % 729, true));
+        assert_eq!(<UMul<UFix<U2, U5>, UFix<U2, U2>> as UWordResult>::reify(), (1, true));
+    }
+
+    #[test]
+    fn fixed_signed_add() {
+        assert_eq!(<IAdd<Tryte<SP243>, Tryte<SP81>> as IWordResult>::reify(), (324, false));
+        assert_eq!(<IAdd<Tryte<SP243>, Tryte<SP243>> as IWordResult>::reify(), (-243, true));
+        assert_eq!(<IAdd<Tryte<SP243>, Tryte<SN81>> as IWordResult>::reify(), (162, false));
+        assert_eq!(<IAdd<Tryte<SN243>, Tryte<SN243>> as IWordResult>::reify(), (243, true));
+        assert_eq!(<IAdd<IFix<U1, SP5>, IFix<U1, SN9>> as IWordResult>::reify(), (-1, true));
+        assert_eq!(<ToInt<IValue<IAdd<Tryte<SP2>, Tryte<SP3>>>> as Int>::reify(), 5);
+        assert!(!<IOverflow<IAdd<Tryte<SP2>, Tryte<SP3>>> as Bool>::reify());
+    }
+
+    #[test]
+    fn fixed_signed_sub_mul() {
+        assert_eq!(<ISub<Tryte<SN243>, Tryte<SP243>> as IWordResult>::reify(), (243, true));
+        assert_eq!(<ISub<Tryte<SP5>, Tryte<SP9>> as IWordResult>::reify(), (-4, false));
+        assert_eq!(<IMul<Tryte<SP81>, Tryte<SN2>> as IWordResult>::reify(), (-162, false));
+        assert_eq!(<IMul<Tryte<SP81>, Tryte<SP5>> as IWordResult>::reify(), (405 - 729, true));
+        assert_eq!(<IMul<Tryte<SN5>, Tryte<S0>> as IWordResult>::reify(), (0, false));
+    }
+
+    #[test]
+    fn fixed_conversions() {
+        let _: UTryte<U9> = <FromNat<U9, U6>>::default();
+        let _: UFix<U2, U0> = <FromNat<U81, U2>>::default();
+        let _: Tryte<SN9> = <FromInt<SN9, U6>>::default();
+        assert_eq!(<FromNat<U243, U3> as UWord>::reify(), 0);
+        assert_eq!(<FromInt<SP5, U1> as IWord>::reify(), -1);
+        assert_eq!(<FromInt<SP243, U5> as IWord>::reify(), 0);
+        assert_eq!(<ToNat<UWord9<U80>> as Nat>::reify(), 80);
+        assert_eq!(<ToInt<Word9<SN81>> as Int>::reify(), -81);
+    }
+}
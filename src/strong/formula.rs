@@ -0,0 +1,193 @@
+//! Type-level evaluation of propositional formulas. Tautology and satisfiability checking work
+//! by enumerating every assignment to the variables, of which there are `2^N` for `N`
+//! variables, so they are only practical for a handful of variables.
+
+pub use types::formula::*;
+
+use strong::boolean::{Bool, BoolList, BoolNil, BoolCons, BoolNot, BoolAnd, BoolOr, BoolIfThen,
+                      False, True, Undefined as BoolUndefined};
+use strong::ternary::{Nat, NatPred, Term, Zero, One, Two};
+
+type_operators! {
+    [A, B, C, D, E]
+
+    /// The element of a `BoolList` at a `Nat` index, counting from zero. Used as `Get<L, N>` or
+    /// `<L as BoolListGet<N>>::Output`. Indices past the end of the list give `Undefined`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Get<{Self}, {B}>`",
+        label = "`Get` is not implemented for these arguments",
+        note = "`Get` takes a `BoolList` and a `Nat`",
+        note = "in generic code, add `{Self}: BoolListGet<{B}>` to the `where` clause"
+    )]
+    (Get) BoolListGet(BoolList, Nat): Bool {
+        forall (N: Nat) {
+            [BoolNil, N] => BoolUndefined
+        }
+        forall (H: Bool, T: BoolList) {
+            [(BoolCons H T), Term] => H
+        }
+        forall (H: Bool, T: BoolList, N: Nat) {
+            [(BoolCons H T), (Zero N)] => (# T (@NatPred (Zero N)))
+            [(BoolCons H T), (One N)] => (# T (@NatPred (One N)))
+            [(BoolCons H T), (Two N)] => (# T (@NatPred (Two N)))
+        }
+    }
+
+    /// Evaluate a formula in an environment, a `BoolList` giving the value of each variable in
+    /// turn. Used as `Eval<F, Env>` or `<F as FormulaEval<Env>>::Output`. If the formula uses a
+    /// variable which the environment doesn't give a value for, the result is `Undefined`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Eval<{Self}, {B}>`",
+        label = "`Eval` is not implemented for these arguments",
+        note = "`Eval` takes a `Formula` and a `BoolList`",
+        note = "in generic code, add `{Self}: FormulaEval<{B}>` to the `where` clause"
+    )]
+    (Eval) FormulaEval(Formula, BoolList): Bool {
+        forall (N: Nat, Env: BoolList) {
+            [(Var N), Env] => (@BoolListGet Env N)
+        }
+        forall (F: Formula, Env: BoolList) {
+            [(FNot F), Env] => (@BoolNot (# F Env))
+        }
+        forall (F: Formula, G: Formula, Env: BoolList) {
+            [(FAnd F G), Env] => (@BoolAnd (# F Env) (# G Env))
+            [(FOr F G), Env] => (@BoolOr (# F Env) (# G Env))
+            [(FImplies F G), Env] => (@BoolIfThen (# F Env) (# G Env))
+        }
+    }
+
+    /// Whether a formula in the variables `Var<U0>` up to but not including `Var<N>` is true
+    /// under every assignment to them. Used as `IsTautology<F, N>` or
+    /// `<F as FormulaIsTautology<N>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IsTautology<{Self}, {B}>`",
+        label = "`IsTautology` is not implemented for these arguments",
+        note = "`IsTautology` takes a `Formula` and a `Nat`",
+        note = "in generic code, add `{Self}: FormulaIsTautology<{B}>` to the `where` clause"
+    )]
+    (IsTautology) FormulaIsTautology(Formula, Nat): Bool {
+        forall (F: Formula, N: Nat) {
+            [F, N] => (@FormulaAllEnvs F N BoolNil)
+        }
+    }
+
+    /// Whether a formula in the variables `Var<U0>` up to but not including `Var<N>` is true
+    /// under some assignment to them. Used as `IsSatisfiable<F, N>` or
+    /// `<F as FormulaIsSatisfiable<N>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IsSatisfiable<{Self}, {B}>`",
+        label = "`IsSatisfiable` is not implemented for these arguments",
+        note = "`IsSatisfiable` takes a `Formula` and a `Nat`",
+        note = "in generic code, add `{Self}: FormulaIsSatisfiable<{B}>` to the `where` clause"
+    )]
+    (IsSatisfiable) FormulaIsSatisfiable(Formula, Nat): Bool {
+        forall (F: Formula, N: Nat) {
+            [F, N] => (@FormulaAnyEnv F N BoolNil)
+        }
+    }
+
+    /// Evaluate a formula under every extension of the environment in the third argument by the
+    /// number of further variables given in the second, and combine the results with `And`.
+    (AllEnvs) FormulaAllEnvs(Formula, Nat, BoolList): Bool {
+        forall (F: Formula, Env: BoolList) {
+            [F, Term, Env] => (@FormulaEval F Env)
+        }
+        forall (F: Formula, N: Nat, Env: BoolList) {
+            [F, (Zero N), Env] => (@BoolAnd
+                (# F (@NatPred (Zero N)) (BoolCons False Env))
+                (# F (@NatPred (Zero N)) (BoolCons True Env)))
+            [F, (One N), Env] => (@BoolAnd
+                (# F (@NatPred (One N)) (BoolCons False Env))
+                (# F (@NatPred (One N)) (BoolCons True Env)))
+            [F, (Two N), Env] => (@BoolAnd
+                (# F (@NatPred (Two N)) (BoolCons False Env))
+                (# F (@NatPred (Two N)) (BoolCons True Env)))
+        }
+    }
+
+    /// As `AllEnvs`, but combining the results with `Or`.
+    (AnyEnv) FormulaAnyEnv(Formula, Nat, BoolList): Bool {
+        forall (F: Formula, Env: BoolList) {
+            [F, Term, Env] => (@FormulaEval F Env)
+        }
+        forall (F: Formula, N: Nat, Env: BoolList) {
+            [F, (Zero N), Env] => (@BoolOr
+                (# F (@NatPred (Zero N)) (BoolCons False Env))
+                (# F (@NatPred (Zero N)) (BoolCons True Env)))
+            [F, (One N), Env] => (@BoolOr
+                (# F (@NatPred (One N)) (BoolCons False Env))
+                (# F (@NatPred (One N)) (BoolCons True Env)))
+            [F, (Two N), Env] => (@BoolOr
+                (# F (@NatPred (Two N)) (BoolCons False Env))
+                (# F (@NatPred (Two N)) (BoolCons True Env)))
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::marker::PhantomData;
+
+    use strong::ternary::{U0, U1, U2, U3};
+    use super::*;
+
+    type X0 = Var<U0>;
+    type X1 = Var<U1>;
+    type X2 = Var<U2>;
+
+    #[test]
+    fn formula_reify() {
+        assert_eq!(<FAnd<X0, FNot<X1>> as Formula>::reify(), "(x0 & !x1)");
+        assert_eq!(<FImplies<FOr<X0, X2>, X1> as Formula>::reify(), "((x0 | x2) -> x1)");
+    }
+
+    #[test]
+    fn formula_get() {
+        type Env = BoolCons<True, BoolCons<False, BoolCons<True>>>;
+
+        assert_eq!(<Env as BoolList>::reify(), vec![true, false, true]);
+        assert!(<Get<Env, U0> as Bool>::reify());
+        assert!(!<Get<Env, U1> as Bool>::reify());
+        assert!(<Get<Env, U2> as Bool>::reify());
+        let _: PhantomData<BoolUndefined> = PhantomData::<Get<Env, U3>>;
+    }
+
+    #[test]
+    fn formula_eval() {
+        type Env = BoolCons<True, BoolCons<False>>;
+
+        assert!(<Eval<X0, Env> as Bool>::reify());
+        assert!(!<Eval<X1, Env> as Bool>::reify());
+        assert!(<Eval<FNot<X1>, Env> as Bool>::reify());
+        assert!(!<Eval<FAnd<X0, X1>, Env> as Bool>::reify());
+        assert!(<Eval<FOr<X0, X1>, Env> as Bool>::reify());
+        assert!(!<Eval<FImplies<X0, X1>, Env> as Bool>::reify());
+        assert!(<Eval<FImplies<X1, X0>, Env> as Bool>::reify());
+    }
+
+    #[test]
+    #[should_panic]
+    fn formula_eval_undefined() {
+        let _ = <Eval<FAnd<X0, X2>, BoolCons<True, BoolCons<True>>> as Bool>::reify();
+    }
+
+    #[test]
+    fn formula_tautology() {
+        assert!(<IsTautology<FOr<X0, FNot<X0>>, U1> as Bool>::reify());
+        assert!(!<IsTautology<FOr<X0, X1>, U2> as Bool>::reify());
+        assert!(<IsTautology<FImplies<FAnd<X0, X1>, X0>, U2> as Bool>::reify());
+        assert!(<IsTautology<FImplies<FAnd<FImplies<X0, X1>, FImplies<X1, X2>>,
+                                      FImplies<X0, X2>>,
+                             U3> as Bool>::reify());
+        assert!(!<IsTautology<FImplies<FImplies<X0, X1>, FImplies<X1, X0>>, U2> as Bool>::reify());
+    }
+
+    #[test]
+    fn formula_satisfiable() {
+        assert!(<IsSatisfiable<FAnd<X0, FNot<X1>>, U2> as Bool>::reify());
+        assert!(!<IsSatisfiable<FAnd<X0, FNot<X0>>, U1> as Bool>::reify());
+        assert!(<IsSatisfiable<FAnd<FOr<X0, X1>, FAnd<FNot<X0>, X2>>, U3> as Bool>::reify());
+        assert!(!<IsSatisfiable<FAnd<FImplies<X0, X1>, FAnd<X0, FNot<X1>>>, U2> as Bool>::reify());
+    }
+}
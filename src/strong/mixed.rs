@@ -0,0 +1,213 @@
+//! Type-level arithmetic between `Nat`s and `Int`s, for offsets applied to lengths and the like.
+//! Each operator works directly on the trits of both arguments, rather than converting one
+//! argument to the other kind first, which would take a second pass over it.
+
+use strong::ternary::{Nat, NatError, NatTriple, NatTriplePlusOne, NatTriplePlusTwo,
+                      Term as NatTerm, Zero as NatZero, One as NatOne, Two as NatTwo,
+                      Undefined as NatUndefined};
+use strong::balanced::{Int, IntError, IntSucc, IntPred, IntTriple, IntAdd, NatErrorToInt,
+                       IntErrorToNat, Term, Zero, Plus, Minus};
+
+type_operators! {
+    [A, B, C, D, E]
+
+    /// Add a signed offset to a `Nat`. Used as `AddSigned<N, I>` or
+    /// `<N as NatAddSigned<I>>::Output`. A result below zero is `Undefined`. An undefined `Nat` is
+    /// propagated, and an undefined `Int` becomes the `Nat` error of the same name.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `AddSigned<{Self}, {B}>`",
+        label = "`AddSigned` is not implemented for these arguments",
+        note = "`AddSigned` takes a `Nat` and an `Int`",
+        note = "a sum below zero is `Undefined`, which a `Defined` bound on the result rejects at \
+            compile time",
+        note = "in generic code, add `{Self}: NatAddSigned<{B}>` to the `where` clause"
+    )]
+    (AddSigned) NatAddSigned(Nat, Int): Nat {
+        [NatTerm, Term] => NatTerm
+        [NatTerm, (Minus Term)] => NatUndefined
+        forall (I: Int) {
+            [NatTerm, (Zero I)] => (@NatTriple (# NatTerm I))
+            [NatTerm, (Plus I)] => (@NatTriplePlusOne (# NatTerm I))
+            [NatTerm, (Minus (Zero I))] => (@NatTriplePlusTwo (# NatTerm (@IntPred (Zero I))))
+            [NatTerm, (Minus (Plus I))] => (@NatTriplePlusTwo (# NatTerm (@IntPred (Plus I))))
+            [NatTerm, (Minus (Minus I))] => (@NatTriplePlusTwo (# NatTerm (@IntPred (Minus I))))
+        }
+        forall (N: Nat) {
+            [(NatZero N), Term] => (@NatTriple N)
+            [(NatOne N), Term] => (NatOne N)
+            [(NatTwo N), Term] => (NatTwo N)
+        }
+        forall (N: Nat, I: Int) {
+            [(NatZero N), (Zero I)] => (@NatTriple (# N I))
+            [(NatZero N), (Plus I)] => (@NatTriplePlusOne (# N I))
+            [(NatZero N), (Minus I)] => (@NatTriplePlusTwo (# N (@IntPred I))) // n0 + iT => 3 * (n + i - 1) + 2
+            [(NatOne N), (Zero I)] => (@NatTriplePlusOne (# N I))
+            [(NatOne N), (Plus I)] => (@NatTriplePlusTwo (# N I))
+            [(NatOne N), (Minus I)] => (@NatTriple (# N I))
+            [(NatTwo N), (Zero I)] => (@NatTriplePlusTwo (# N I))
+            [(NatTwo N), (Plus I)] => (@NatTriple (# N (@IntSucc I))) // n2 + i1 => 3 * (n + i + 1) + 0
+            [(NatTwo N), (Minus I)] => (@NatTriplePlusOne (# N I))
+        }
+        forall (I: Int, U: NatError) {
+            [U, I] => U
+        }
+        forall (U: IntError) {
+            [NatTerm, U] => (@IntErrorToNat U)
+        }
+        forall (N: Nat, U: IntError) {
+            [(NatZero N), U] => (@IntErrorToNat U)
+            [(NatOne N), U] => (@IntErrorToNat U)
+            [(NatTwo N), U] => (@IntErrorToNat U)
+        }
+    }
+
+    /// The signed difference of two `Nat`s, as an `Int`. Used as `Diff<X, Y>` or
+    /// `<X as NatDiff<Y>>::Output`. Unlike `ternary::Sub`, this never underflows: `Diff<U2, U5>`
+    /// is `SN3`. If either `Nat` is undefined, so is the result, as described for
+    /// `IntFromNatError`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Diff<{Self}, {B}>`",
+        label = "`Diff` is not implemented for these arguments",
+        note = "`Diff` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatDiff<{B}>` to the `where` clause"
+    )]
+    (Diff) NatDiff(Nat, Nat): Int {
+        [NatTerm, NatTerm] => Term
+        forall (Y: Nat) {
+            [NatTerm, (NatZero Y)] => (@IntTriple (# NatTerm Y))
+            [NatTerm, (NatOne Y)] => (Minus (# NatTerm Y))
+            [NatTerm, (NatTwo Y)] => (Plus (@IntPred (# NatTerm Y)))
+        }
+        forall (X: Nat) {
+            [(NatZero X), NatTerm] => (@IntTriple (# X NatTerm))
+            [(NatOne X), NatTerm] => (Plus (# X NatTerm))
+            [(NatTwo X), NatTerm] => (Minus (@IntSucc (# X NatTerm)))
+        }
+        forall (X: Nat, Y: Nat) {
+            [(NatZero X), (NatZero Y)] => (@IntTriple (# X Y))
+            [(NatZero X), (NatOne Y)] => (Minus (# X Y))
+            [(NatZero X), (NatTwo Y)] => (Plus (@IntPred (# X Y))) // x0 - y2 => 3 * (x - y - 1) + 1
+            [(NatOne X), (NatZero Y)] => (Plus (# X Y))
+            [(NatOne X), (NatOne Y)] => (@IntTriple (# X Y))
+            [(NatOne X), (NatTwo Y)] => (Minus (# X Y))
+            [(NatTwo X), (NatZero Y)] => (Minus (@IntSucc (# X Y))) // x2 - y0 => 3 * (x - y + 1) - 1
+            [(NatTwo X), (NatOne Y)] => (Plus (# X Y))
+            [(NatTwo X), (NatTwo Y)] => (@IntTriple (# X Y))
+        }
+        forall (Y: Nat, U: NatError) {
+            [U, Y] => (@NatErrorToInt U)
+        }
+        forall (U: NatError) {
+            [NatTerm, U] => (@NatErrorToInt U)
+        }
+        forall (X: Nat, U: NatError) {
+            [(NatZero X), U] => (@NatErrorToInt U)
+            [(NatOne X), U] => (@NatErrorToInt U)
+            [(NatTwo X), U] => (@NatErrorToInt U)
+        }
+    }
+
+    /// Multiply an `Int` by a `Nat`. Used as `ScaleSigned<I, N>` or
+    /// `<I as IntScaleSigned<N>>::Output`. An undefined `Int` is propagated, and an undefined
+    /// `Nat` becomes an `Int` error as described for `IntFromNatError`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `ScaleSigned<{Self}, {B}>`",
+        label = "`ScaleSigned` is not implemented for these arguments",
+        note = "`ScaleSigned` takes an `Int` and a `Nat`",
+        note = "in generic code, add `{Self}: IntScaleSigned<{B}>` to the `where` clause"
+    )]
+    (ScaleSigned) IntScaleSigned(Int, Nat): Int {
+        [Term, NatTerm] => Term
+        forall (I: Int) {
+            [(Zero I), NatTerm] => Term
+            [(Plus I), NatTerm] => Term
+            [(Minus I), NatTerm] => Term
+        }
+        forall (I: Int, N: Nat) {
+            [I, (NatZero N)] => (@IntTriple (# I N)) // i * n0 => 3 * (i * n) + 0
+            [I, (NatOne N)] => (@IntAdd (@IntTriple (# I N)) I) // i * n1 => 3 * (i * n) + i
+            [I, (NatTwo N)] => (@IntAdd (@IntTriple (# I N)) (@IntAdd I I)) // i * n2 => 3 * (i * n) + 2i
+        }
+        forall (U: IntError) {
+            [U, NatTerm] => U
+        }
+        forall (I: Int, U: NatError) {
+            [I, U] => (@NatErrorToInt U)
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use strong::boolean::Bool;
+    use strong::balanced::{self, IsCanonical, SN9, SN7, SN5, SN4, SN3, SN2,
+                           SN1, S0, SP1, SP2, SP3, SP4, SP7, SP9, SP10, SP26, SP81};
+    use strong::ternary::{self, Underflow, U0, U1, U2, U3, U4, U5, U7, U9, U10, U11, U13, U23, U26,
+                          U27, U81};
+    use super::*;
+
+    #[test]
+    fn mixed_add_signed() {
+        assert_eq!(<AddSigned<U5, SN2> as Nat>::reify(), 3);
+        assert_eq!(<AddSigned<U4, SP7> as Nat>::reify(), 11);
+        assert_eq!(<AddSigned<U2, SP9> as Nat>::reify(), 11);
+        assert_eq!(<AddSigned<U26, SP1> as Nat>::reify(), 27);
+        assert_eq!(<AddSigned<U27, SN1> as Nat>::reify(), 26);
+        assert_eq!(<AddSigned<U13, SN9> as Nat>::reify(), 4);
+        assert_eq!(<AddSigned<U0, SP26> as Nat>::reify(), 26);
+        assert_eq!(<AddSigned<U81, S0> as Nat>::reify(), 81);
+        let _: ternary::Term = <AddSigned<U9, SN9>>::default();
+        let _: U7 = <AddSigned<U10, SN3>>::default();
+        let _: U23 = <AddSigned<U3, balanced::Add<SP10, SP10>>>::default();
+    }
+
+    #[test]
+    fn mixed_add_signed_underflow() {
+        let _: ternary::Undefined = <AddSigned<U0, SN1>>::default();
+        let _: ternary::Undefined = <AddSigned<U1, SN2>>::default();
+        let _: ternary::Undefined = <AddSigned<U4, SN5>>::default();
+        let _: ternary::Undefined = <AddSigned<U0, SN9>>::default();
+        let _: ternary::DivByZero = <AddSigned<U3, balanced::Div<SP1, S0>>>::default();
+        let _: ternary::Undefined = <AddSigned<U3, balanced::Undefined>>::default();
+        let _: Underflow<U1, U2> = <AddSigned<ternary::Sub<U1, U2>, SP3>>::default();
+    }
+
+    #[test]
+    fn mixed_diff() {
+        assert_eq!(<Diff<U10, U3> as Int>::reify(), 7);
+        assert_eq!(<Diff<U3, U10> as Int>::reify(), -7);
+        assert_eq!(<Diff<U0, U81> as Int>::reify(), -81);
+        assert_eq!(<Diff<U26, U0> as Int>::reify(), 26);
+        assert_eq!(<Diff<U27, U26> as Int>::reify(), 1);
+        assert_eq!(<Diff<U2, U11> as Int>::reify(), -9);
+        let _: balanced::Term = <Diff<U13, U13>>::default();
+        let _: SN4 = <Diff<U5, U9>>::default();
+        let _: SP4 = <Diff<U9, U5>>::default();
+        let _: balanced::DivByZero = <Diff<ternary::Div<U1, U0>, U2>>::default();
+        let _: balanced::Undefined = <Diff<U2, ternary::Sub<U1, U2>>>::default();
+    }
+
+    #[test]
+    fn mixed_scale_signed() {
+        assert_eq!(<ScaleSigned<SN2, U5> as Int>::reify(), -10);
+        assert_eq!(<ScaleSigned<SP3, U9> as Int>::reify(), 27);
+        assert_eq!(<ScaleSigned<SN7, U13> as Int>::reify(), -91);
+        assert_eq!(<ScaleSigned<SP81, U2> as Int>::reify(), 162);
+        let _: balanced::Term = <ScaleSigned<SP3, U0>>::default();
+        let _: balanced::Term = <ScaleSigned<S0, U7>>::default();
+        let _: SN9 = <ScaleSigned<SN3, U3>>::default();
+        let _: balanced::DivByZero = <ScaleSigned<balanced::Div<SP1, S0>, U2>>::default();
+        let _: balanced::Undefined = <ScaleSigned<SP2, ternary::Sub<U1, U2>>>::default();
+        let _: balanced::DivByZero = <ScaleSigned<SP2, ternary::Rem<U1, U0>>>::default();
+    }
+
+    #[test]
+    fn mixed_canonical() {
+        assert!(<IsCanonical<Diff<U27, U27>> as Bool>::reify());
+        assert!(<IsCanonical<Diff<U1, U10>> as Bool>::reify());
+        assert!(<IsCanonical<ScaleSigned<SN1, U27>> as Bool>::reify());
+        assert!(<ternary::IsCanonical<AddSigned<U11, SN2>> as Bool>::reify());
+        assert!(<ternary::IsCanonical<AddSigned<U27, SN1>> as Bool>::reify());
+    }
+}
@@ -3,5 +3,16 @@
 //! and you haven't listed it as a trait bound in a `where` clause.
 
 pub mod boolean;
+pub mod ordering;
 pub mod ternary;
 pub mod balanced;
+pub mod trits;
+pub mod fixed;
+pub mod trilean;
+pub mod formula;
+pub mod assert;
+pub mod proof;
+pub mod option;
+pub mod result;
+pub mod num;
+pub mod mixed;
@@ -0,0 +1,316 @@
+//! Kind-generic type operators, implemented for both `Nat`s and `Int`s, so that an algorithm
+//! which only needs arithmetic and comparisons can be written once. `NumAdd<Y>` is implemented by
+//! every `Nat` for `Nat`s `Y`, with the same result as `NatAdd<Y>`, and by every `Int` for `Int`s
+//! `Y`, with the same result as `IntAdd<Y>`; and so on for the other operators. Both operands must
+//! be of the same kind.
+//!
+//! ```
+//! use type_level_logic::strong::num::{Add, Mul};
+//! use type_level_logic::strong::ternary::{Nat, U2, U3};
+//! use type_level_logic::strong::balanced::{Int, SN2, SP3};
+//!
+//! type SumOfSquares<X, Y> = Add<Mul<X, X>, Mul<Y, Y>>;
+//!
+//! assert_eq!(<SumOfSquares<U2, U3> as Nat>::reify(), 13);
+//! assert_eq!(<SumOfSquares<SN2, SP3> as Int>::reify(), 13);
+//! ```
+//!
+//! These traits have to be implemented constructor by constructor, because blanket
+//! implementations for all `Nat`s and for all `Int`s would overlap as far as the compiler knows.
+//! A new number representation joins in by listing its constructors and operators in an
+//! invocation of the `impl_num!` macro at the bottom of this module.
+
+use strong::boolean::Bool;
+use strong::ordering::Ordering;
+use strong::ternary::{self, Nat, NatSucc, NatPred, NatAdd, NatSub, NatMul, NatDiv, NatRem, NatMin,
+                      NatMax, NatCompare, NatCmp, NatIsDefined};
+use strong::balanced::{self, Int, IntSucc, IntPred, IntAdd, IntSub, IntMul, IntDiv, IntRem,
+                       IntMin, IntMax, IntCompare, IntCmp, IntIsDefined};
+
+/// The `Num` kind contains every `Nat` and every `Int`, including their error values.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a type-level number",
+    label = "expected a `Nat` or an `Int` here",
+    note = "if `{Self}` is a type parameter, add a `{Self}: Num` bound to the `where` clause"
+)]
+pub trait Num {}
+
+/// Add one to a number. Used as `Succ<X>` or `<X as NumSucc>::Output`.
+#[diagnostic::on_unimplemented(
+    message = "cannot evaluate `Succ<{Self}>`",
+    label = "`Succ` is not implemented for this argument",
+    note = "`Succ` takes a `Nat` or an `Int`",
+    note = "in generic code, add `{Self}: NumSucc` to the `where` clause"
+)]
+pub trait NumSucc: Num {
+    type Output: Num;
+}
+
+/// Subtract one from a number. Used as `Pred<X>` or `<X as NumPred>::Output`. `Pred` of a zero
+/// `Nat` underflows.
+#[diagnostic::on_unimplemented(
+    message = "cannot evaluate `Pred<{Self}>`",
+    label = "`Pred` is not implemented for this argument",
+    note = "`Pred` takes a `Nat` or an `Int`",
+    note = "in generic code, add `{Self}: NumPred` to the `where` clause"
+)]
+pub trait NumPred: Num {
+    type Output: Num;
+}
+
+/// Add two numbers. Used as `Add<X, Y>` or `<X as NumAdd<Y>>::Output`.
+#[diagnostic::on_unimplemented(
+    message = "cannot evaluate `Add<{Self}, {Y}>`",
+    label = "`Add` is not implemented for these arguments",
+    note = "`Add` takes two `Nat`s or two `Int`s, not one of each",
+    note = "in generic code, add `{Self}: NumAdd<{Y}>` to the `where` clause"
+)]
+pub trait NumAdd<Y>: Num {
+    type Output: Num;
+}
+
+/// Subtract one number from another. Used as `Sub<X, Y>` or `<X as NumSub<Y>>::Output`. A `Nat`
+/// subtraction below zero underflows.
+#[diagnostic::on_unimplemented(
+    message = "cannot evaluate `Sub<{Self}, {Y}>`",
+    label = "`Sub` is not implemented for these arguments",
+    note = "`Sub` takes two `Nat`s or two `Int`s, not one of each",
+    note = "in generic code, add `{Self}: NumSub<{Y}>` to the `where` clause"
+)]
+pub trait NumSub<Y>: Num {
+    type Output: Num;
+}
+
+/// Multiply two numbers. Used as `Mul<X, Y>` or `<X as NumMul<Y>>::Output`.
+#[diagnostic::on_unimplemented(
+    message = "cannot evaluate `Mul<{Self}, {Y}>`",
+    label = "`Mul` is not implemented for these arguments",
+    note = "`Mul` takes two `Nat`s or two `Int`s, not one of each",
+    note = "in generic code, add `{Self}: NumMul<{Y}>` to the `where` clause"
+)]
+pub trait NumMul<Y>: Num {
+    type Output: Num;
+}
+
+/// Divide one number by another. Used as `Div<X, Y>` or `<X as NumDiv<Y>>::Output`. `Int`
+/// division rounds to the nearest integer, as `balanced::Div` does.
+#[diagnostic::on_unimplemented(
+    message = "cannot evaluate `Div<{Self}, {Y}>`",
+    label = "`Div` is not implemented for these arguments",
+    note = "`Div` takes two `Nat`s or two `Int`s, not one of each",
+    note = "in generic code, add `{Self}: NumDiv<{Y}>` to the `where` clause"
+)]
+pub trait NumDiv<Y>: Num {
+    type Output: Num;
+}
+
+/// The remainder of dividing one number by another. Used as `Rem<X, Y>` or
+/// `<X as NumRem<Y>>::Output`.
+#[diagnostic::on_unimplemented(
+    message = "cannot evaluate `Rem<{Self}, {Y}>`",
+    label = "`Rem` is not implemented for these arguments",
+    note = "`Rem` takes two `Nat`s or two `Int`s, not one of each",
+    note = "in generic code, add `{Self}: NumRem<{Y}>` to the `where` clause"
+)]
+pub trait NumRem<Y>: Num {
+    type Output: Num;
+}
+
+/// The lesser of two numbers. Used as `Min<X, Y>` or `<X as NumMin<Y>>::Output`.
+#[diagnostic::on_unimplemented(
+    message = "cannot evaluate `Min<{Self}, {Y}>`",
+    label = "`Min` is not implemented for these arguments",
+    note = "`Min` takes two `Nat`s or two `Int`s, not one of each",
+    note = "in generic code, add `{Self}: NumMin<{Y}>` to the `where` clause"
+)]
+pub trait NumMin<Y>: Num {
+    type Output: Num;
+}
+
+/// The greater of two numbers. Used as `Max<X, Y>` or `<X as NumMax<Y>>::Output`.
+#[diagnostic::on_unimplemented(
+    message = "cannot evaluate `Max<{Self}, {Y}>`",
+    label = "`Max` is not implemented for these arguments",
+    note = "`Max` takes two `Nat`s or two `Int`s, not one of each",
+    note = "in generic code, add `{Self}: NumMax<{Y}>` to the `where` clause"
+)]
+pub trait NumMax<Y>: Num {
+    type Output: Num;
+}
+
+/// Compare two numbers, giving an `Ordering`. Used as `Compare<X, Y>` or
+/// `<X as NumCompare<Y>>::Output`.
+#[diagnostic::on_unimplemented(
+    message = "cannot evaluate `Compare<{Self}, {Y}>`",
+    label = "`Compare` is not implemented for these arguments",
+    note = "`Compare` takes two `Nat`s or two `Int`s, not one of each",
+    note = "in generic code, add `{Self}: NumCompare<{Y}>` to the `where` clause"
+)]
+pub trait NumCompare<Y>: Num {
+    type Output: Ordering;
+}
+
+/// Select one of three numbers by comparing two others: `Cmp<X, Y, L, E, G>` is `L` if `X` is
+/// less than `Y`, `E` if they are equal and `G` if `X` is greater. Used as `Cmp<X, Y, L, E, G>`
+/// or `<X as NumCmp<Y, L, E, G>>::Output`. All five must be of the same kind.
+#[diagnostic::on_unimplemented(
+    message = "cannot evaluate `Cmp<{Self}, {Y}, {L}, {E}, {G}>`",
+    label = "`Cmp` is not implemented for these arguments",
+    note = "`Cmp` takes five `Nat`s or five `Int`s",
+    note = "in generic code, add `{Self}: NumCmp<{Y}, {L}, {E}, {G}>` to the `where` clause"
+)]
+pub trait NumCmp<Y, L, E, G>: Num {
+    type Output: Num;
+}
+
+/// Whether a number is defined, rather than an error value. Used as `IsDefined<X>` or
+/// `<X as NumIsDefined>::Output`.
+#[diagnostic::on_unimplemented(
+    message = "cannot evaluate `IsDefined<{Self}>`",
+    label = "`IsDefined` is not implemented for this argument",
+    note = "`IsDefined` takes a `Nat` or an `Int`",
+    note = "in generic code, add `{Self}: NumIsDefined` to the `where` clause"
+)]
+pub trait NumIsDefined: Num {
+    type Output: Bool;
+}
+
+pub type Succ<X> = <X as NumSucc>::Output;
+pub type Pred<X> = <X as NumPred>::Output;
+pub type Add<X, Y> = <X as NumAdd<Y>>::Output;
+pub type Sub<X, Y> = <X as NumSub<Y>>::Output;
+pub type Mul<X, Y> = <X as NumMul<Y>>::Output;
+pub type Div<X, Y> = <X as NumDiv<Y>>::Output;
+pub type Rem<X, Y> = <X as NumRem<Y>>::Output;
+pub type Min<X, Y> = <X as NumMin<Y>>::Output;
+pub type Max<X, Y> = <X as NumMax<Y>>::Output;
+pub type Compare<X, Y> = <X as NumCompare<Y>>::Output;
+pub type Cmp<X, Y, L, E, G> = <X as NumCmp<Y, L, E, G>>::Output;
+pub type IsDefined<X> = <X as NumIsDefined>::Output;
+
+
+// For each constructor `$t` of the kind `$kind`, with type parameters `$n`, implement `Num` and
+// forward each `Num` operator `$op` to the kind's own operator `$kop`, whose other arguments `$p`
+// are of the same kind, and whose result is of the kind `$out`.
+macro_rules! impl_num {
+    ($kind:ident $ops:tt; $($gens:tt $t:ty),*) => {
+        $(
+            impl_num!(@ctor $kind $gens $t; $ops);
+        )*
+    };
+    (@ctor $kind:ident $gens:tt $t:ty;
+     { $($op:ident $ps:tt => $kop:ident: $out:ident),* }) => {
+        impl_num!(@num $kind $gens $t);
+
+        $(
+            impl_num!(@op $kind $gens $t; $op $ps => $kop: $out);
+        )*
+    };
+    (@num $kind:ident [$($n:ident),*] $t:ty) => {
+        impl<$($n: $kind),*> Num for $t {}
+    };
+    (@op $kind:ident [$($n:ident),*] $t:ty;
+     $op:ident [$($p:ident),*] => $kop:ident: $out:ident) => {
+        impl<$($n: $kind,)* $($p: $kind),*> $op<$($p),*> for $t
+            where $t: $kop<$($p),*>, <$t as $kop<$($p),*>>::Output: $out
+        {
+            type Output = <$t as $kop<$($p),*>>::Output;
+        }
+    };
+}
+
+impl_num! {
+    Nat {
+        NumSucc [] => NatSucc: Num,
+        NumPred [] => NatPred: Num,
+        NumAdd [Y] => NatAdd: Num,
+        NumSub [Y] => NatSub: Num,
+        NumMul [Y] => NatMul: Num,
+        NumDiv [Y] => NatDiv: Num,
+        NumRem [Y] => NatRem: Num,
+        NumMin [Y] => NatMin: Num,
+        NumMax [Y] => NatMax: Num,
+        NumCompare [Y] => NatCompare: Ordering,
+        NumCmp [Y, Lt, Eq, Gt] => NatCmp: Num,
+        NumIsDefined [] => NatIsDefined: Bool
+    };
+    [] ternary::Term,
+    [N] ternary::Zero<N>,
+    [N] ternary::One<N>,
+    [N] ternary::Two<N>,
+    [] ternary::Undefined,
+    [] ternary::DivByZero,
+    [L, R] ternary::Underflow<L, R>
+}
+
+impl_num! {
+    Int {
+        NumSucc [] => IntSucc: Num,
+        NumPred [] => IntPred: Num,
+        NumAdd [Y] => IntAdd: Num,
+        NumSub [Y] => IntSub: Num,
+        NumMul [Y] => IntMul: Num,
+        NumDiv [Y] => IntDiv: Num,
+        NumRem [Y] => IntRem: Num,
+        NumMin [Y] => IntMin: Num,
+        NumMax [Y] => IntMax: Num,
+        NumCompare [Y] => IntCompare: Ordering,
+        NumCmp [Y, Lt, Eq, Gt] => IntCmp: Num,
+        NumIsDefined [] => IntIsDefined: Bool
+    };
+    [] balanced::Term,
+    [N] balanced::Zero<N>,
+    [N] balanced::Plus<N>,
+    [N] balanced::Minus<N>,
+    [] balanced::Undefined,
+    [] balanced::DivByZero
+}
+
+
+#[cfg(test)]
+mod tests {
+    use strong::balanced::{SN9, SN5, SN2, SN1, S0, SP1, SP2, SP3, SP4, SP7};
+    use strong::ternary::{Underflow, U0, U1, U2, U3, U4, U5, U7, U9, U10};
+    use super::*;
+
+    // Written once, for both kinds.
+    type SumOfSquares<X, Y> = Add<Mul<X, X>, Mul<Y, Y>>;
+    type Clamp<X, Lo, Hi> = Min<Max<X, Lo>, Hi>;
+    type AbsDiff<X, Y> = Cmp<X, Y, Sub<Y, X>, Sub<X, X>, Sub<X, Y>>;
+
+    fn dist<X, Y>() -> bool where X: NumCmp<Y, Sub<Y, X>, Sub<X, X>, Sub<X, Y>> + NumSub<Y> +
+                                       NumSub<X>,
+                                   Y: NumSub<X>,
+                                   AbsDiff<X, Y>: NumIsDefined
+    {
+        <IsDefined<AbsDiff<X, Y>> as Bool>::reify()
+    }
+
+    #[test]
+    fn num_nat() {
+        assert_eq!(<Succ<U4> as Nat>::reify(), 5);
+        assert_eq!(<Pred<U4> as Nat>::reify(), 3);
+        assert_eq!(<Div<U9, U2> as Nat>::reify(), 4);
+        assert_eq!(<Rem<U9, U2> as Nat>::reify(), 1);
+        assert_eq!(<SumOfSquares<U2, U3> as Nat>::reify(), 13);
+        assert_eq!(<Clamp<U10, U2, U7> as Nat>::reify(), 7);
+        assert_eq!(<AbsDiff<U3, U10> as Nat>::reify(), 7);
+        assert_eq!(<Compare<U3, U5> as Ordering>::reify(), ::std::cmp::Ordering::Less);
+        let _: Underflow<U0, U1> = <Pred<U0>>::default();
+        assert!(!<IsDefined<Sub<U1, U2>> as Bool>::reify());
+        assert!(dist::<U9, U1>());
+    }
+
+    #[test]
+    fn num_int() {
+        assert_eq!(<Succ<SN1> as Int>::reify(), 0);
+        assert_eq!(<Pred<S0> as Int>::reify(), -1);
+        assert_eq!(<Div<SP7, SP2> as Int>::reify(), <balanced::Div<SP7, SP2> as Int>::reify());
+        assert_eq!(<SumOfSquares<SN2, SP3> as Int>::reify(), 13);
+        assert_eq!(<Clamp<SN9, SN2, SP4> as Int>::reify(), -2);
+        assert_eq!(<AbsDiff<SP3, SN5> as Int>::reify(), 8);
+        assert_eq!(<Compare<SP1, SN1> as Ordering>::reify(), ::std::cmp::Ordering::Greater);
+        assert!(!<IsDefined<Rem<SP3, S0>> as Bool>::reify());
+        assert!(dist::<SN5, SP1>());
+    }
+}
@@ -0,0 +1,216 @@
+//! Type-level operations on optional types. Functions are passed to `Map` and `AndThen` as types
+//! implementing `Apply`, since a type operator cannot itself be passed as a type: a function is a
+//! zero-sized type `F` for which `<F as Apply<T>>::Output` is the result of applying it to `T`.
+
+pub use types::option::*;
+
+use strong::boolean::{Bool, False, True};
+use strong::ternary::{Nat, NatIsDefined};
+use strong::balanced::{Int, IntIsDefined};
+
+/// A type-level function, which maps `T` to `<Self as Apply<T>>::Output`. Implement it for a
+/// zero-sized type to pass a function to `Map` or `AndThen`; for example, a function which doubles
+/// `Nat`s is
+///
+/// ```
+/// use type_level_logic::strong::option::Apply;
+/// use type_level_logic::strong::ternary::{Nat, NatMul, Mul, U2};
+///
+/// struct Double;
+///
+/// impl<N: Nat + NatMul<U2>> Apply<N> for Double {
+///     type Output = Mul<N, U2>;
+/// }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be applied to `{T}`",
+    label = "this is not a type-level function accepting `{T}`",
+    note = "implement `Apply<{T}>` for `{Self}`, or check the kind of `{T}`"
+)]
+pub trait Apply<T> {
+    type Output;
+}
+
+type_operators! {
+    [A, B, C, D, E]
+
+    /// Apply a function to the contents of an `Option`. Used as `Map<O, F>` or
+    /// `<O as OptionMap<F>>::Output`; `Map<Some<T>, F>` is `Some<<F as Apply<T>>::Output>`, and
+    /// `Map<None, F>` is `None`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Map<{Self}, {B}>`",
+        label = "`Map` is not implemented for these arguments",
+        note = "`Map` takes an `Option` and a function `F` implementing `Apply`",
+        note = "in generic code, add `{Self}: OptionMap<{B}>` to the `where` clause"
+    )]
+    (Map) OptionMap(Option, _): Option {
+        forall (F: Sized) {
+            [None, F] => None
+        }
+        forall (T: Sized, F: Sized) {
+            [(Some T), F] => (Some (@Apply F T))
+        }
+    }
+
+    /// Apply a function which returns an `Option` to the contents of an `Option`. Used as
+    /// `AndThen<O, F>` or `<O as OptionAndThen<F>>::Output`; `AndThen<Some<T>, F>` is
+    /// `<F as Apply<T>>::Output`, and `AndThen<None, F>` is `None`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `AndThen<{Self}, {B}>`",
+        label = "`AndThen` is not implemented for these arguments",
+        note = "`AndThen` takes an `Option` and a function `F` implementing `Apply`",
+        note = "in generic code, add `{Self}: OptionAndThen<{B}>` to the `where` clause"
+    )]
+    (AndThen) OptionAndThen(Option, _): Option {
+        forall (F: Sized) {
+            [None, F] => None
+        }
+        forall (T: Sized, F: Sized) {
+            [(Some T), F] => (& (@Apply F T) where (<F as Apply<T>>::Output: Option))
+        }
+    }
+
+    /// The contents of an `Option`, or a default. Used as `UnwrapOr<O, D>` or
+    /// `<O as OptionUnwrapOr<D>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `UnwrapOr<{Self}, {B}>`",
+        label = "`UnwrapOr` is not implemented for these arguments",
+        note = "`UnwrapOr` takes an `Option` and a type of any kind",
+        note = "in generic code, add `{Self}: OptionUnwrapOr<{B}>` to the `where` clause"
+    )]
+    (UnwrapOr) OptionUnwrapOr(Option, _): _ {
+        forall (D: Sized) {
+            [None, D] => D
+        }
+        forall (T: Sized, D: Sized) {
+            [(Some T), D] => T
+        }
+    }
+
+    /// The contents of an `Option`. Used as `Unwrap<O>` or `<O as OptionUnwrap>::Output`.
+    /// `Unwrap<None>` is a compile error, just as `Option::unwrap` panics on `None`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Unwrap<{Self}>`",
+        label = "`Unwrap` is not implemented for these arguments",
+        note = "`Unwrap` takes an `Option`",
+        note = "`None` has no contents to unwrap; use `UnwrapOr` to supply a default",
+        note = "in generic code, add `{Self}: OptionUnwrap` to the `where` clause"
+    )]
+    (Unwrap) OptionUnwrap(Option): _ {
+        forall (T: Sized) {
+            [(Some T)] => T
+        }
+    }
+
+    /// Whether an `Option` is `Some`. Used as `IsSome<O>` or `<O as OptionIsSome>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IsSome<{Self}>`",
+        label = "`IsSome` is not implemented for these arguments",
+        note = "`IsSome` takes an `Option`",
+        note = "in generic code, add `{Self}: OptionIsSome` to the `where` clause"
+    )]
+    (IsSome) OptionIsSome(Option): Bool {
+        [None] => False
+        forall (T: Sized) {
+            [(Some T)] => True
+        }
+    }
+
+    /// `Some<T>` if a `Bool` is `True`, and `None` if it is `False`. Used as `SomeIf<B, T>` or
+    /// `<B as OptionSomeIf<T>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `SomeIf<{Self}, {B}>`",
+        label = "`SomeIf` is not implemented for these arguments",
+        note = "`SomeIf` takes a `Bool` and a type of any kind",
+        note = "in generic code, add `{Self}: OptionSomeIf<{B}>` to the `where` clause"
+    )]
+    (SomeIf) OptionSomeIf(Bool, _): Option {
+        forall (T: Sized) {
+            [True, T] => (Some T)
+            [False, T] => None
+        }
+    }
+
+    /// `Some<N>` if the `Nat` `N` is defined, and `None` if it is an error value. Used as
+    /// `FromNat<N>` or `<N as NatToOption>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `FromNat<{Self}>`",
+        label = "`FromNat` is not implemented for these arguments",
+        note = "`FromNat` takes a `Nat`",
+        note = "in generic code, add `{Self}: NatToOption` to the `where` clause"
+    )]
+    (FromNat) NatToOption(Nat): Option {
+        forall (N: Nat) {
+            [N] => (@OptionSomeIf (@NatIsDefined N) N)
+        }
+    }
+
+    /// `Some<N>` if the `Int` `N` is defined, and `None` if it is an error value. Used as
+    /// `FromInt<N>` or `<N as IntToOption>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `FromInt<{Self}>`",
+        label = "`FromInt` is not implemented for these arguments",
+        note = "`FromInt` takes an `Int`",
+        note = "in generic code, add `{Self}: IntToOption` to the `where` clause"
+    )]
+    (FromInt) IntToOption(Int): Option {
+        forall (N: Int) {
+            [N] => (@OptionSomeIf (@IntIsDefined N) N)
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use strong::balanced::{self, SN2, SP3};
+    use strong::ternary::{Mul, NatMul, NatPred, Pred, Sub, U0, U1, U2, U3, U4, U5, U6};
+    use super::*;
+
+    struct Double;
+
+    impl<N: Nat + NatMul<U2>> Apply<N> for Double {
+        type Output = Mul<N, U2>;
+    }
+
+    struct CheckedPred;
+
+    impl<N: Nat + NatPred> Apply<N> for CheckedPred where Pred<N>: NatToOption {
+        type Output = FromNat<Pred<N>>;
+    }
+
+    #[test]
+    fn option_map() {
+        let _: Some<U6> = <Map<Some<U3>, Double>>::default();
+        let _: None = <Map<None, Double>>::default();
+        assert_eq!(<Unwrap<Map<Some<U2>, Double>> as Nat>::reify(), 4);
+    }
+
+    #[test]
+    fn option_and_then() {
+        let _: Some<U4> = <AndThen<Some<U5>, CheckedPred>>::default();
+        let _: None = <AndThen<Some<U0>, CheckedPred>>::default();
+        let _: None = <AndThen<None, CheckedPred>>::default();
+        let _: Some<U0> = <AndThen<AndThen<Some<U2>, CheckedPred>, CheckedPred>>::default();
+        let _: None = <AndThen<AndThen<Some<U1>, CheckedPred>, CheckedPred>>::default();
+    }
+
+    #[test]
+    fn option_unwrap() {
+        assert_eq!(<UnwrapOr<Some<U3>, U5> as Nat>::reify(), 3);
+        assert_eq!(<UnwrapOr<None, U5> as Nat>::reify(), 5);
+        assert_eq!(<UnwrapOr<FromInt<balanced::Div<SP3, SN2>>, SP3> as Int>::reify(), -1);
+        assert_eq!(<UnwrapOr<FromInt<balanced::Div<SP3, balanced::S0>>, SP3> as Int>::reify(), 3);
+        assert!(<Unwrap<Some<True>> as Bool>::reify());
+    }
+
+    #[test]
+    fn option_is_some() {
+        assert!(<IsSome<Some<U0>> as Bool>::reify());
+        assert!(!<IsSome<None> as Bool>::reify());
+        assert!(<IsSome<FromNat<Sub<U5, U2>>> as Bool>::reify());
+        assert!(!<IsSome<FromNat<Sub<U2, U5>>> as Bool>::reify());
+        assert!(!<IsSome<SomeIf<False, U1>> as Bool>::reify());
+        let _: Some<U1> = <SomeIf<True, U1>>::default();
+    }
+}
@@ -0,0 +1,40 @@
+//! Type-level operations on orderings.
+
+pub use types::ordering::*;
+
+type_operators! {
+    [A, B, C, D, E]
+
+    /// Select one of three types depending on an `Ordering`: the first if it is `Less`, the
+    /// second if it is `Equal` and the third if it is `Greater`. Use as `Match<O, L, E, G>` or
+    /// `<O as OrdMatch<L, E, G>>::Output`. The selected types may be of any kind, so a comparison
+    /// computed once with `Compare` can be used to choose between `Nat`s, `Int`s, pairs or
+    /// anything else.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Match<{Self}, {B}, {C}, {D}>`",
+        label = "`Match` is not implemented for these arguments",
+        note = "`Match` takes an `Ordering` and three types of any kind",
+        note = "in generic code, add `{Self}: OrdMatch<{B}, {C}, {D}>` to the `where` clause"
+    )]
+    (Match) OrdMatch(Ordering, _, _, _): _ {
+        forall (L: Sized, E: Sized, G: Sized) {
+            [Less, L, E, G] => L
+            [Equal, L, E, G] => E
+            [Greater, L, E, G] => G
+        }
+    }
+
+    /// Reverse an `Ordering`, swapping `Less` and `Greater`. Use as `Reverse<O>` or
+    /// `<O as OrdReverse>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Reverse<{Self}>`",
+        label = "`Reverse` is not implemented for these arguments",
+        note = "`Reverse` takes an `Ordering`",
+        note = "in generic code, add `{Self}: OrdReverse` to the `where` clause"
+    )]
+    (Reverse) OrdReverse(Ordering): Ordering {
+        [Less] => Greater
+        [Equal] => Equal
+        [Greater] => Less
+    }
+}
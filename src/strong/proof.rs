@@ -0,0 +1,220 @@
+//! Value-level evidence for type-level facts. A `Proof<F>` is a zero-sized token which can only be
+//! constructed when the fact `F` holds, so a function can ask for a `Proof` parameter instead of
+//! re-stating the bounds which establish the fact, and a caller builds the proof once, where the
+//! types are concrete. The facts are `Lt<A, B>`, that `A` is less than `B`; `Eq<A, B>`, that `A`
+//! and `B` are equal; and `Divides<A, B>`, that the `Nat` `A` divides the `Nat` `B`. `Lt` and
+//! `Eq` apply to `Nat`s and `Int`s alike.
+//!
+//! Proofs are checked with `Proof::new`, which requires that the fact `Holds`. They can also be
+//! derived from other proofs with lemmas such as `Proof::trans`, which need no further bounds
+//! because the conclusion follows from the premises. Lemmas which introduce a new number, such as
+//! `Proof::add_nat`, are associated functions taking their premise as an argument, and are called
+//! as `Proof::add_nat::<C>(p)`: through method-call syntax, the compiler would search the
+//! operator's implementations before it knew `C`, and overflow.
+//!
+//! ```
+//! use type_level_logic::strong::proof::{Proof, Lt};
+//! use type_level_logic::strong::ternary::{Nat, U2, U3, U7};
+//!
+//! // An index which is known to be in bounds, without restating the comparison.
+//! fn index<I: Nat, N: Nat>(_: Proof<Lt<I, N>>) -> usize {
+//!     I::reify()
+//! }
+//!
+//! let two_three: Proof<Lt<U2, U3>> = Proof::new();
+//! assert_eq!(index(two_three.trans(Proof::<Lt<U3, U7>>::new())), 2);
+//! ```
+//!
+//! A fact which does not hold has no proof:
+//!
+//! ```compile_fail
+//! use type_level_logic::strong::proof::{Proof, Lt};
+//! use type_level_logic::strong::ternary::{U3, U7};
+//!
+//! let _: Proof<Lt<U7, U3>> = Proof::new();
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use strong::assert::{AssertEq, AssertLt};
+use strong::ternary::{self, Nat, NatAdd, NatMul, NatRem};
+use strong::balanced::{self, Int, IntAdd};
+
+/// The fact that `A` is less than `B`.
+pub struct Lt<A, B>(PhantomData<(A, B)>);
+
+/// The fact that `A` and `B` are equal.
+pub struct Eq<A, B>(PhantomData<(A, B)>);
+
+/// The fact that the `Nat` `A` divides the `Nat` `B`, leaving no remainder. Zero divides nothing,
+/// since a remainder by zero is not defined.
+pub struct Divides<A, B>(PhantomData<(A, B)>);
+
+/// Implemented by the facts which hold. Used as `F: Holds`, and required by `Proof::new`.
+#[diagnostic::on_unimplemented(
+    message = "the fact `{Self}` does not hold",
+    label = "no proof of this fact can be constructed",
+    note = "`Lt` and `Eq` compare `Nat`s or `Int`s, and `Divides` relates two `Nat`s"
+)]
+pub trait Holds {}
+
+impl<A, B> Holds for Lt<A, B> where A: AssertLt<B> {}
+
+impl<A, B> Holds for Eq<A, B> where A: AssertEq<B> {}
+
+impl<A, B> Holds for Divides<A, B>
+    where A: Nat, B: Nat + NatRem<A>, <B as NatRem<A>>::Output: RemIsZero {}
+
+/// The test underlying `Divides`, which must leave a remainder of zero.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "the division leaves a remainder of `{Self}`",
+    label = "this does not divide evenly"
+)]
+pub trait RemIsZero {}
+
+impl RemIsZero for ternary::Term {}
+
+/// Zero-sized evidence that the fact `F` holds.
+pub struct Proof<F> {
+    _fact: PhantomData<F>,
+}
+
+impl<F> Proof<F> {
+    // Only for lemmas, whose conclusions follow from their premises.
+    fn assume() -> Self {
+        Proof { _fact: PhantomData }
+    }
+}
+
+impl<F: Holds> Proof<F> {
+    /// Prove a fact by checking it at compile time.
+    pub fn new() -> Self {
+        Proof::assume()
+    }
+}
+
+impl<F: Holds> Default for Proof<F> {
+    fn default() -> Self {
+        Proof::new()
+    }
+}
+
+impl<F> Clone for Proof<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for Proof<F> {}
+
+impl<F> fmt::Debug for Proof<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Proof")
+    }
+}
+
+impl<A, B> Proof<Lt<A, B>> {
+    /// If `A < B` and `B < C`, then `A < C`.
+    pub fn trans<C>(self, _: Proof<Lt<B, C>>) -> Proof<Lt<A, C>> {
+        Proof::assume()
+    }
+
+    /// If `A < B` and `B = C`, then `A < C`.
+    pub fn rewrite<C>(self, _: Proof<Eq<B, C>>) -> Proof<Lt<A, C>> {
+        Proof::assume()
+    }
+
+    /// If `A < B`, then `A + C < B + C`, for `Nat`s.
+    pub fn add_nat<C: Nat>(_: Self) -> Proof<Lt<ternary::Add<A, C>, ternary::Add<B, C>>>
+        where A: Nat + NatAdd<C>, B: Nat + NatAdd<C>
+    {
+        Proof::assume()
+    }
+
+    /// If `A < B`, then `A + C < B + C`, for `Int`s.
+    pub fn add_int<C: Int>(_: Self) -> Proof<Lt<balanced::Add<A, C>, balanced::Add<B, C>>>
+        where A: Int + IntAdd<C>, B: Int + IntAdd<C>
+    {
+        Proof::assume()
+    }
+}
+
+impl<A, B> Proof<Eq<A, B>> {
+    /// If `A = B`, then `B = A`.
+    pub fn symm(self) -> Proof<Eq<B, A>> {
+        Proof::assume()
+    }
+
+    /// If `A = B` and `B = C`, then `A = C`.
+    pub fn trans<C>(self, _: Proof<Eq<B, C>>) -> Proof<Eq<A, C>> {
+        Proof::assume()
+    }
+}
+
+impl<A, B> Proof<Divides<A, B>> {
+    /// If `A` divides `B` and `B` divides `C`, then `A` divides `C`.
+    pub fn trans<C>(self, _: Proof<Divides<B, C>>) -> Proof<Divides<A, C>> {
+        Proof::assume()
+    }
+
+    /// If `A` divides `B`, then `A` divides `B * C`.
+    pub fn mul<C: Nat>(_: Self) -> Proof<Divides<A, ternary::Mul<B, C>>>
+        where B: Nat + NatMul<C>
+    {
+        Proof::assume()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use strong::balanced::{SN5, SN2, SP3};
+    use strong::ternary::{Add, U0, U1, U2, U3, U4, U5, U6, U7, U9, U12, U36};
+    use super::*;
+
+    fn below<I: Nat, N: Nat>(_: Proof<Lt<I, N>>) -> (usize, usize) {
+        (I::reify(), N::reify())
+    }
+
+    fn below_int<I: Int, N: Int>(_: Proof<Lt<I, N>>) -> (isize, isize) {
+        (I::reify(), N::reify())
+    }
+
+    #[test]
+    fn proof_lt() {
+        let p: Proof<Lt<U2, U5>> = Proof::new();
+        let q: Proof<Lt<U5, U9>> = Proof::new();
+        assert_eq!(below(p), (2, 5));
+        assert_eq!(below(p.trans(q)), (2, 9));
+        assert_eq!(below(Proof::add_nat::<U4>(p)), (6, 9));
+        assert_eq!(below(p.rewrite(Proof::<Eq<U5, Add<U1, U4>>>::new())), (2, 5));
+
+        let s: Proof<Lt<SN5, SN2>> = Proof::new();
+        assert_eq!(below_int(s), (-5, -2));
+        assert_eq!(below_int(Proof::add_int::<SP3>(s)), (-2, 1));
+        assert_eq!(below_int(s.trans(Proof::<Lt<SN2, SP3>>::new())), (-5, 3));
+    }
+
+    #[test]
+    fn proof_eq() {
+        let p: Proof<Eq<Add<U2, U5>, U7>> = Proof::new();
+        let _: Proof<Eq<U7, Add<U2, U5>>> = p.symm();
+        let _: Proof<Eq<Add<U2, U5>, Add<U3, U4>>> = p.trans(Proof::<Eq<U7, Add<U3, U4>>>::new());
+    }
+
+    #[test]
+    fn proof_divides() {
+        let p: Proof<Divides<U3, U6>> = Proof::new();
+        let q: Proof<Divides<U6, U12>> = Proof::default();
+        let _: Proof<Divides<U3, U12>> = p.trans(q);
+        let _: Proof<Divides<U3, U36>> = Proof::new();
+        let r: Proof<Divides<U6, U36>> = Proof::mul::<U3>(q);
+        let _: Proof<Divides<U3, U36>> = p.trans(r);
+        let _: Proof<Divides<U3, U0>> = Proof::mul::<U0>(p);
+        let _: Proof<Divides<U5, U0>> = Proof::new();
+        assert_eq!(format!("{:?}", p), "Proof");
+        assert_eq!(::std::mem::size_of::<Proof<Divides<U3, U6>>>(), 0);
+    }
+}
@@ -0,0 +1,210 @@
+//! Type-level operations on results. Functions are passed to `Map` and `AndThen` as types
+//! implementing `Apply`, as for the operators on `Option`s.
+
+pub use types::result::*;
+
+use strong::boolean::{Bool, False, True};
+use strong::option::{Apply, Option, None, Some};
+use strong::ternary::{Nat, NatIsDefined};
+use strong::balanced::{Int, IntIsDefined};
+
+type_operators! {
+    [A, B, C, D, E]
+
+    /// Apply a function to the contents of an `Ok`. Used as `Map<R, F>` or
+    /// `<R as ResultMap<F>>::Output`; `Map<Ok<T>, F>` is `Ok<<F as Apply<T>>::Output>`, and an
+    /// `Err` is left unchanged.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Map<{Self}, {B}>`",
+        label = "`Map` is not implemented for these arguments",
+        note = "`Map` takes a `Result` and a function `F` implementing `Apply`",
+        note = "in generic code, add `{Self}: ResultMap<{B}>` to the `where` clause"
+    )]
+    (Map) ResultMap(Result, _): Result {
+        forall (E: Sized, F: Sized) {
+            [(Err E), F] => (Err E)
+        }
+        forall (T: Sized, F: Sized) {
+            [(Ok T), F] => (Ok (@Apply F T))
+        }
+    }
+
+    /// Apply a function which returns a `Result` to the contents of an `Ok`. Used as
+    /// `AndThen<R, F>` or `<R as ResultAndThen<F>>::Output`; `AndThen<Ok<T>, F>` is
+    /// `<F as Apply<T>>::Output`, and an `Err` is left unchanged.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `AndThen<{Self}, {B}>`",
+        label = "`AndThen` is not implemented for these arguments",
+        note = "`AndThen` takes a `Result` and a function `F` implementing `Apply`",
+        note = "in generic code, add `{Self}: ResultAndThen<{B}>` to the `where` clause"
+    )]
+    (AndThen) ResultAndThen(Result, _): Result {
+        forall (E: Sized, F: Sized) {
+            [(Err E), F] => (Err E)
+        }
+        forall (T: Sized, F: Sized) {
+            [(Ok T), F] => (& (@Apply F T) where (<F as Apply<T>>::Output: Result))
+        }
+    }
+
+    /// The contents of an `Ok`, or a default. Used as `UnwrapOr<R, D>` or
+    /// `<R as ResultUnwrapOr<D>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `UnwrapOr<{Self}, {B}>`",
+        label = "`UnwrapOr` is not implemented for these arguments",
+        note = "`UnwrapOr` takes a `Result` and a type of any kind",
+        note = "in generic code, add `{Self}: ResultUnwrapOr<{B}>` to the `where` clause"
+    )]
+    (UnwrapOr) ResultUnwrapOr(Result, _): _ {
+        forall (E: Sized, D: Sized) {
+            [(Err E), D] => D
+        }
+        forall (T: Sized, D: Sized) {
+            [(Ok T), D] => T
+        }
+    }
+
+    /// The contents of an `Ok`. Used as `Unwrap<R>` or `<R as ResultUnwrap>::Output`.
+    /// `Unwrap<Err<E>>` is a compile error, just as `Result::unwrap` panics on an `Err`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Unwrap<{Self}>`",
+        label = "`Unwrap` is not implemented for these arguments",
+        note = "`Unwrap` takes a `Result`",
+        note = "an `Err` has no contents to unwrap; use `UnwrapOr` to supply a default",
+        note = "in generic code, add `{Self}: ResultUnwrap` to the `where` clause"
+    )]
+    (Unwrap) ResultUnwrap(Result): _ {
+        forall (T: Sized) {
+            [(Ok T)] => T
+        }
+    }
+
+    /// Whether a `Result` is `Ok`. Used as `IsOk<R>` or `<R as ResultIsOk>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IsOk<{Self}>`",
+        label = "`IsOk` is not implemented for these arguments",
+        note = "`IsOk` takes a `Result`",
+        note = "in generic code, add `{Self}: ResultIsOk` to the `where` clause"
+    )]
+    (IsOk) ResultIsOk(Result): Bool {
+        forall (T: Sized) {
+            [(Ok T)] => True
+            [(Err T)] => False
+        }
+    }
+
+    /// The contents of an `Ok` as an `Option`, discarding any error. Used as `ToOption<R>` or
+    /// `<R as ResultToOption>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `ToOption<{Self}>`",
+        label = "`ToOption` is not implemented for these arguments",
+        note = "`ToOption` takes a `Result`",
+        note = "in generic code, add `{Self}: ResultToOption` to the `where` clause"
+    )]
+    (ToOption) ResultToOption(Result): Option {
+        forall (T: Sized) {
+            [(Ok T)] => (Some T)
+            [(Err T)] => None
+        }
+    }
+
+    /// `Ok<T>` if a `Bool` is `True`, and `Err<T>` if it is `False`. Used as `OkIf<B, T>` or
+    /// `<B as ResultOkIf<T>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `OkIf<{Self}, {B}>`",
+        label = "`OkIf` is not implemented for these arguments",
+        note = "`OkIf` takes a `Bool` and a type of any kind",
+        note = "in generic code, add `{Self}: ResultOkIf<{B}>` to the `where` clause"
+    )]
+    (OkIf) ResultOkIf(Bool, _): Result {
+        forall (T: Sized) {
+            [True, T] => (Ok T)
+            [False, T] => (Err T)
+        }
+    }
+
+    /// `Ok<N>` if the `Nat` `N` is defined, and `Err<N>` if it is an error value such as
+    /// `Underflow<L, R>`. Used as `FromNat<N>` or `<N as NatToResult>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `FromNat<{Self}>`",
+        label = "`FromNat` is not implemented for these arguments",
+        note = "`FromNat` takes a `Nat`",
+        note = "in generic code, add `{Self}: NatToResult` to the `where` clause"
+    )]
+    (FromNat) NatToResult(Nat): Result {
+        forall (N: Nat) {
+            [N] => (@ResultOkIf (@NatIsDefined N) N)
+        }
+    }
+
+    /// `Ok<N>` if the `Int` `N` is defined, and `Err<N>` if it is an error value such as
+    /// `DivByZero`. Used as `FromInt<N>` or `<N as IntToResult>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `FromInt<{Self}>`",
+        label = "`FromInt` is not implemented for these arguments",
+        note = "`FromInt` takes an `Int`",
+        note = "in generic code, add `{Self}: IntToResult` to the `where` clause"
+    )]
+    (FromInt) IntToResult(Int): Result {
+        forall (N: Int) {
+            [N] => (@ResultOkIf (@IntIsDefined N) N)
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use strong::balanced::{self, SN2, SP3};
+    use strong::ternary::{Div, DivByZero, Mul, NatMul, NatSub, Sub, Underflow, U0, U1, U2, U3,
+                          U4, U5, U6};
+    use super::*;
+
+    struct Double;
+
+    impl<N: Nat + NatMul<U2>> Apply<N> for Double {
+        type Output = Mul<N, U2>;
+    }
+
+    struct CheckedSub3;
+
+    impl<N: Nat + NatSub<U3>> Apply<N> for CheckedSub3 where Sub<N, U3>: NatToResult {
+        type Output = FromNat<Sub<N, U3>>;
+    }
+
+    #[test]
+    fn result_map() {
+        let _: Ok<U6> = <Map<Ok<U3>, Double>>::default();
+        let _: Err<U1> = <Map<Err<U1>, Double>>::default();
+        assert_eq!(<Unwrap<Map<Ok<U2>, Double>> as Nat>::reify(), 4);
+    }
+
+    #[test]
+    fn result_and_then() {
+        let _: Ok<U2> = <AndThen<Ok<U5>, CheckedSub3>>::default();
+        let _: Err<Underflow<U1, U3>> = <AndThen<Ok<U1>, CheckedSub3>>::default();
+        let _: Err<Underflow<U2, U3>> =
+            <AndThen<AndThen<Ok<U5>, CheckedSub3>, CheckedSub3>>::default();
+        let _: Err<DivByZero> = <AndThen<FromNat<Div<U4, U0>>, CheckedSub3>>::default();
+    }
+
+    #[test]
+    fn result_unwrap() {
+        assert_eq!(<UnwrapOr<Ok<U3>, U5> as Nat>::reify(), 3);
+        assert_eq!(<UnwrapOr<Err<U3>, U5> as Nat>::reify(), 5);
+        assert_eq!(<UnwrapOr<FromInt<balanced::Sub<SN2, SP3>>, SP3> as Int>::reify(), -5);
+        assert_eq!(<UnwrapOr<FromInt<balanced::Div<SP3, balanced::S0>>, SP3> as Int>::reify(), 3);
+        assert!(!<Unwrap<Ok<False>> as Bool>::reify());
+    }
+
+    #[test]
+    fn result_is_ok() {
+        assert!(<IsOk<Ok<U0>> as Bool>::reify());
+        assert!(!<IsOk<Err<U0>> as Bool>::reify());
+        assert!(<IsOk<FromNat<Sub<U5, U2>>> as Bool>::reify());
+        assert!(!<IsOk<FromNat<Sub<U2, U5>>> as Bool>::reify());
+        let _: Some<U4> = <ToOption<Ok<U4>>>::default();
+        let _: None = <ToOption<FromNat<Div<U4, U0>>>>::default();
+        let _: Err<U1> = <OkIf<False, U1>>::default();
+    }
+}
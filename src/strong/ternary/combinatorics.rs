@@ -0,0 +1,188 @@
+//! Combinatorial type operators on `Nat`s: factorials, permutations, binomial coefficients and
+//! Fibonacci numbers. These grow quickly, and the multiplications and divisions involved get
+//! expensive for the type-checker just as quickly, so it's best to keep the inputs small.
+
+use super::*;
+
+type_operators! {
+    [A, B, C, D, E, F, G, H]
+
+    /// The factorial of a `Nat`. Used as `Factorial<N>` or `<N as NatFactorial>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Factorial<{Self}>`",
+        label = "`Factorial` is not implemented for these arguments",
+        note = "`Factorial` takes a `Nat`",
+        note = "in generic code, add `{Self}: NatFactorial` to the `where` clause"
+    )]
+    (Factorial) NatFactorial(Nat): Nat {
+        [Term] => One
+        forall (N: Nat) {
+            [(Zero N)] => (@NatMul (Zero N) (# (@NatPred (Zero N))))
+            [(One N)] => (@NatMul (One N) (# (@NatPred (One N))))
+            [(Two N)] => (@NatMul (Two N) (# (@NatPred (Two N))))
+        }
+        forall (U: NatError) {
+            [U] => U
+        }
+    }
+
+    /// The number of ways to choose an ordered sequence of `K` elements out of `N`; that is, the
+    /// falling factorial `N! / (N - K)!`. Used as `Permutations<N, K>` or
+    /// `<N as NatPermutations<K>>::Output`. Undefined if `K` is greater than `N`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Permutations<{Self}, {B}>`",
+        label = "`Permutations` is not implemented for these arguments",
+        note = "`Permutations` takes two `Nat`s",
+        note = "`{B}` items cannot be arranged out of only `{Self}`, so `{B}` greater than \
+            `{Self}` gives `Undefined`",
+        note = "in generic code, add `{Self}: NatPermutations<{B}>` to the `where` clause"
+    )]
+    (Permutations) NatPermutations(Nat, Nat): Nat {
+        [Term, Term] => One
+        forall (X: Nat) {
+            [Term, (Zero X)] => Undefined
+            [Term, (One X)] => Undefined
+            [Term, (Two X)] => Undefined
+            [(Zero X), Term] => One
+            [(One X), Term] => One
+            [(Two X), Term] => One
+        }
+        forall (X: Nat, Y: Nat) {
+            [(Zero X), (Zero Y)] => (@NatMul (Zero X) (# (@NatPred (Zero X)) (@NatPred (Zero Y))))
+            [(Zero X), (One Y)] => (@NatMul (Zero X) (# (@NatPred (Zero X)) (@NatPred (One Y))))
+            [(Zero X), (Two Y)] => (@NatMul (Zero X) (# (@NatPred (Zero X)) (@NatPred (Two Y))))
+            [(One X), (Zero Y)] => (@NatMul (One X) (# (@NatPred (One X)) (@NatPred (Zero Y))))
+            [(One X), (One Y)] => (@NatMul (One X) (# (@NatPred (One X)) (@NatPred (One Y))))
+            [(One X), (Two Y)] => (@NatMul (One X) (# (@NatPred (One X)) (@NatPred (Two Y))))
+            [(Two X), (Zero Y)] => (@NatMul (Two X) (# (@NatPred (Two X)) (@NatPred (Zero Y))))
+            [(Two X), (One Y)] => (@NatMul (Two X) (# (@NatPred (Two X)) (@NatPred (One Y))))
+            [(Two X), (Two Y)] => (@NatMul (Two X) (# (@NatPred (Two X)) (@NatPred (Two Y))))
+        }
+        forall (Y: Nat, U: NatError) {
+            [U, Y] => U
+        }
+        forall (U: NatError) {
+            [Term, U] => U
+        }
+        forall (X: Nat, U: NatError) {
+            [(Zero X), U] => U
+            [(One X), U] => U
+            [(Two X), U] => U
+        }
+    }
+
+    /// The binomial coefficient "`N` choose `K`". Used as `Binomial<N, K>` or
+    /// `<N as NatBinomial<K>>::Output`. Undefined if `K` is greater than `N`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Binomial<{Self}, {B}>`",
+        label = "`Binomial` is not implemented for these arguments",
+        note = "`Binomial` takes two `Nat`s",
+        note = "choosing `{B}` items from `{Self}` is `Undefined` when `{B}` is the larger",
+        note = "in generic code, add `{Self}: NatBinomial<{B}>` to the `where` clause"
+    )]
+    (Binomial) NatBinomial(Nat, Nat): Nat {
+        forall (N: Nat, K: Nat) {
+            [N, K] => (@NatDiv (@NatPermutations N K) (@NatFactorial K))
+        }
+    }
+
+    /// The `N`th Fibonacci number, where `Fibonacci<U0> = U0` and `Fibonacci<U1> = U1`. Used as
+    /// `Fibonacci<N>` or `<N as NatFibonacci>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Fibonacci<{Self}>`",
+        label = "`Fibonacci` is not implemented for these arguments",
+        note = "`Fibonacci` takes a `Nat`",
+        note = "in generic code, add `{Self}: NatFibonacci` to the `where` clause"
+    )]
+    (Fibonacci) NatFibonacci(Nat): Nat {
+        forall (N: Nat) {
+            [N] => (@Nat2P1 (@NatFibonacciInternal N))
+        }
+    }
+
+    (FibonacciInternal) NatFibonacciInternal(Nat): NatPair {
+        [Term] => (Nat2 Term One)
+        forall (N: Nat) {
+            [(Zero N)] => (@NatFibonacciStep (# (@NatPred (Zero N))))
+            [(One N)] => (@NatFibonacciStep (# (@NatPred (One N))))
+            [(Two N)] => (@NatFibonacciStep (# (@NatPred (Two N))))
+        }
+        forall (U: NatError) {
+            [U] => (Nat2 U U)
+        }
+    }
+
+    (FibonacciStep) NatFibonacciStep(NatPair): NatPair {
+        forall (X: Nat, Y: Nat) {
+            [(Nat2 X Y)] => (Nat2 Y (@NatAdd X Y))
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ternary_factorial() {
+        assert_eq!(<Factorial<U0> as Nat>::reify(), 1);
+        assert_eq!(<Factorial<U1> as Nat>::reify(), 1);
+        assert_eq!(<Factorial<U2> as Nat>::reify(), 2);
+        assert_eq!(<Factorial<U3> as Nat>::reify(), 6);
+        assert_eq!(<Factorial<U4> as Nat>::reify(), 24);
+        assert_eq!(<Factorial<U5> as Nat>::reify(), 120);
+        assert_eq!(<Factorial<U7> as Nat>::reify(), 5040);
+    }
+
+    #[test]
+    fn ternary_permutations() {
+        assert_eq!(<Permutations<U0, U0> as Nat>::reify(), 1);
+        assert_eq!(<Permutations<U5, U0> as Nat>::reify(), 1);
+        assert_eq!(<Permutations<U5, U1> as Nat>::reify(), 5);
+        assert_eq!(<Permutations<U5, U2> as Nat>::reify(), 20);
+        assert_eq!(<Permutations<U5, U5> as Nat>::reify(), 120);
+        assert_eq!(<Permutations<U9, U3> as Nat>::reify(), 504);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ternary_permutations_undefined() {
+        let _: Undefined = <Permutations<U3, U4>>::default();
+        let _ = <Permutations<U0, U1> as Nat>::reify();
+    }
+
+    #[test]
+    fn ternary_binomial() {
+        assert_eq!(<Binomial<U0, U0> as Nat>::reify(), 1);
+        assert_eq!(<Binomial<U4, U2> as Nat>::reify(), 6);
+        assert_eq!(<Binomial<U5, U0> as Nat>::reify(), 1);
+        assert_eq!(<Binomial<U5, U3> as Nat>::reify(), 10);
+        assert_eq!(<Binomial<U6, U6> as Nat>::reify(), 1);
+        assert_eq!(<Binomial<U8, U4> as Nat>::reify(), 70);
+        assert_eq!(<Binomial<U10, U3> as Nat>::reify(), 120);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ternary_binomial_undefined() {
+        let _: Undefined = <Binomial<U2, U5>>::default();
+        let _ = <Binomial<U3, U4> as Nat>::reify();
+    }
+
+    #[test]
+    fn ternary_fibonacci() {
+        assert_eq!(<Fibonacci<U0> as Nat>::reify(), 0);
+        assert_eq!(<Fibonacci<U1> as Nat>::reify(), 1);
+        assert_eq!(<Fibonacci<U2> as Nat>::reify(), 1);
+        assert_eq!(<Fibonacci<U3> as Nat>::reify(), 2);
+        assert_eq!(<Fibonacci<U7> as Nat>::reify(), 13);
+        assert_eq!(<Fibonacci<U12> as Nat>::reify(), 144);
+        assert_eq!(<Fibonacci<U20> as Nat>::reify(), 6765);
+    }
+
+    #[test]
+    fn ternary_combinatorics_reexported() {
+        assert_eq!(<::strong::ternary::Binomial<U5, U2> as Nat>::reify(), 10);
+    }
+}
@@ -0,0 +1,421 @@
+//! Type operators which work on the individual trits of a `Nat`: its length, digit sum, digit
+//! counts, trailing zeros and most significant trit, as well as trit-wise logic in the manner of
+//! bitwise operations. Like `NatRev`, these all walk the trits from least to most significant.
+
+use super::*;
+
+type_operators! {
+    [A, B, C, D, E, F, G, H]
+
+    /// The number of trits in a `Nat`, as a `Nat`. Used as `TritLen<N>` or
+    /// `<N as NatTritLen>::Output`. Zero has no trits, so `TritLen<U0> = U0`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `TritLen<{Self}>`",
+        label = "`TritLen` is not implemented for these arguments",
+        note = "`TritLen` takes a `Nat`",
+        note = "in generic code, add `{Self}: NatTritLen` to the `where` clause"
+    )]
+    (TritLen) NatTritLen(Nat): Nat {
+        [Term] => Term
+        forall (N: Nat) {
+            [(Zero N)] => (@NatSucc (# N))
+            [(One N)] => (@NatSucc (# N))
+            [(Two N)] => (@NatSucc (# N))
+        }
+        forall (U: NatError) {
+            [U] => U
+        }
+    }
+
+    /// The sum of the trits of a `Nat`. Used as `DigitSum<N>` or `<N as NatDigitSum>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `DigitSum<{Self}>`",
+        label = "`DigitSum` is not implemented for these arguments",
+        note = "`DigitSum` takes a `Nat`",
+        note = "in generic code, add `{Self}: NatDigitSum` to the `where` clause"
+    )]
+    (DigitSum) NatDigitSum(Nat): Nat {
+        [Term] => Term
+        forall (N: Nat) {
+            [(Zero N)] => (# N)
+            [(One N)] => (@NatSucc (# N))
+            [(Two N)] => (@NatSucc (@NatSucc (# N)))
+        }
+        forall (U: NatError) {
+            [U] => U
+        }
+    }
+
+    /// The number of trits of a `Nat` which are equal to `D`, where `D` is one of `U0`, `U1` or
+    /// `U2`. Used as `CountDigit<N, D>` or `<N as NatCountDigit<D>>::Output`. Since canonical
+    /// `Nat`s have no leading zeros, `CountDigit<N, U0>` counts only the zeros below the most
+    /// significant trit.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `CountDigit<{Self}, {B}>`",
+        label = "`CountDigit` is not implemented for these arguments",
+        note = "`CountDigit` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatCountDigit<{B}>` to the `where` clause"
+    )]
+    (CountDigit) NatCountDigit(Nat, Nat): Nat {
+        forall (D: Nat) {
+            [Term, D] => Term
+        }
+        forall (N: Nat) {
+            [(Zero N), Term] => (@NatSucc (# N Term))
+            [(Zero N), One] => (# N One)
+            [(Zero N), Two] => (# N Two)
+            [(One N), Term] => (# N Term)
+            [(One N), One] => (@NatSucc (# N One))
+            [(One N), Two] => (# N Two)
+            [(Two N), Term] => (# N Term)
+            [(Two N), One] => (# N One)
+            [(Two N), Two] => (@NatSucc (# N Two))
+        }
+        forall (D: Nat, U: NatError) {
+            [U, D] => U
+        }
+    }
+
+    /// The number of trailing zero trits of a `Nat`; that is, the largest `K` such that `3^K`
+    /// divides `N`. Used as `TrailingZeros<N>` or `<N as NatTrailingZeros>::Output`. Every power
+    /// of three divides zero, so `TrailingZeros<U0>` is `Undefined`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `TrailingZeros<{Self}>`",
+        label = "`TrailingZeros` is not implemented for these arguments",
+        note = "`TrailingZeros` takes a `Nat`",
+        note = "`TrailingZeros<U0>` is `Undefined`, since every power of three divides zero",
+        note = "in generic code, add `{Self}: NatTrailingZeros` to the `where` clause"
+    )]
+    (TrailingZeros) NatTrailingZeros(Nat): Nat {
+        [Term] => Undefined
+        forall (N: Nat) {
+            [(Zero N)] => (@NatSucc (# N))
+            [(One N)] => Term
+            [(Two N)] => Term
+        }
+        forall (U: NatError) {
+            [U] => U
+        }
+    }
+
+    /// The most significant nonzero trit of a `Nat`, as one of `U1` or `U2`, or `U0` if the
+    /// `Nat` is zero. Used as `MostSignificantTrit<N>` or
+    /// `<N as NatMostSignificantTrit>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `MostSignificantTrit<{Self}>`",
+        label = "`MostSignificantTrit` is not implemented for these arguments",
+        note = "`MostSignificantTrit` takes a `Nat`",
+        note = "in generic code, add `{Self}: NatMostSignificantTrit` to the `where` clause"
+    )]
+    (MostSignificantTrit) NatMostSignificantTrit(Nat): Nat {
+        forall (N: Nat) {
+            [N] => (@NatMostSignificantTritInternal N Term)
+        }
+    }
+
+    /// Walk the trits of a `Nat` from least to most significant, keeping the last nonzero trit
+    /// seen.
+    (MostSignificantTritInternal) NatMostSignificantTritInternal(Nat, Nat): Nat {
+        forall (T: Nat) {
+            [Term, T] => T
+        }
+        forall (N: Nat, T: Nat) {
+            [(Zero N), T] => (# N T)
+            [(One N), T] => (# N One)
+            [(Two N), T] => (# N Two)
+        }
+        forall (T: Nat, U: NatError) {
+            [U, T] => U
+        }
+    }
+
+    /// Trit-wise minimum of two `Nat`s, the ternary analogue of bitwise AND. Used as
+    /// `TritAnd<X, Y>` or `<X as NatTritAnd<Y>>::Output`. The shorter `Nat` is padded with zero
+    /// trits, and the result is canonical.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `TritAnd<{Self}, {B}>`",
+        label = "`TritAnd` is not implemented for these arguments",
+        note = "`TritAnd` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatTritAnd<{B}>` to the `where` clause"
+    )]
+    (TritAnd) NatTritAnd(Nat, Nat): Nat {
+        [Term, Term] => Term
+        forall (N: Nat) {
+            [Term, (Zero N)] => (# (Zero Term) (Zero N))
+            [Term, (One N)] => (# (Zero Term) (One N))
+            [Term, (Two N)] => (# (Zero Term) (Two N))
+            [(Zero N), Term] => (# (Zero N) (Zero Term))
+            [(One N), Term] => (# (One N) (Zero Term))
+            [(Two N), Term] => (# (Two N) (Zero Term))
+        }
+        forall (M: Nat, N: Nat) {
+            [(Zero M), (Zero N)] => (@NatTriple (# M N))
+            [(Zero M), (One N)] => (@NatTriple (# M N))
+            [(Zero M), (Two N)] => (@NatTriple (# M N))
+            [(One M), (Zero N)] => (@NatTriple (# M N))
+            [(One M), (One N)] => (One (# M N))
+            [(One M), (Two N)] => (One (# M N))
+            [(Two M), (Zero N)] => (@NatTriple (# M N))
+            [(Two M), (One N)] => (One (# M N))
+            [(Two M), (Two N)] => (Two (# M N))
+        }
+        forall (Y: Nat, U: NatError) {
+            [U, Y] => U
+        }
+        forall (U: NatError) {
+            [Term, U] => U
+        }
+        forall (N: Nat, U: NatError) {
+            [(Zero N), U] => U
+            [(One N), U] => U
+            [(Two N), U] => U
+        }
+    }
+
+    /// Trit-wise maximum of two `Nat`s, the ternary analogue of bitwise OR. Used as
+    /// `TritOr<X, Y>` or `<X as NatTritOr<Y>>::Output`. The shorter `Nat` is padded with zero
+    /// trits, and the result is canonical.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `TritOr<{Self}, {B}>`",
+        label = "`TritOr` is not implemented for these arguments",
+        note = "`TritOr` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatTritOr<{B}>` to the `where` clause"
+    )]
+    (TritOr) NatTritOr(Nat, Nat): Nat {
+        [Term, Term] => Term
+        forall (N: Nat) {
+            [Term, (Zero N)] => (# (Zero Term) (Zero N))
+            [Term, (One N)] => (# (Zero Term) (One N))
+            [Term, (Two N)] => (# (Zero Term) (Two N))
+            [(Zero N), Term] => (# (Zero N) (Zero Term))
+            [(One N), Term] => (# (One N) (Zero Term))
+            [(Two N), Term] => (# (Two N) (Zero Term))
+        }
+        forall (M: Nat, N: Nat) {
+            [(Zero M), (Zero N)] => (@NatTriple (# M N))
+            [(Zero M), (One N)] => (One (# M N))
+            [(Zero M), (Two N)] => (Two (# M N))
+            [(One M), (Zero N)] => (One (# M N))
+            [(One M), (One N)] => (One (# M N))
+            [(One M), (Two N)] => (Two (# M N))
+            [(Two M), (Zero N)] => (Two (# M N))
+            [(Two M), (One N)] => (Two (# M N))
+            [(Two M), (Two N)] => (Two (# M N))
+        }
+        forall (Y: Nat, U: NatError) {
+            [U, Y] => U
+        }
+        forall (U: NatError) {
+            [Term, U] => U
+        }
+        forall (N: Nat, U: NatError) {
+            [(Zero N), U] => U
+            [(One N), U] => U
+            [(Two N), U] => U
+        }
+    }
+
+    /// Trit-wise sum modulo three of two `Nat`s, the ternary analogue of bitwise XOR; that is,
+    /// addition without carries. Used as `TritXor<X, Y>` or `<X as NatTritXor<Y>>::Output`. The
+    /// shorter `Nat` is padded with zero trits, and the result is canonical.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `TritXor<{Self}, {B}>`",
+        label = "`TritXor` is not implemented for these arguments",
+        note = "`TritXor` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatTritXor<{B}>` to the `where` clause"
+    )]
+    (TritXor) NatTritXor(Nat, Nat): Nat {
+        [Term, Term] => Term
+        forall (N: Nat) {
+            [Term, (Zero N)] => (# (Zero Term) (Zero N))
+            [Term, (One N)] => (# (Zero Term) (One N))
+            [Term, (Two N)] => (# (Zero Term) (Two N))
+            [(Zero N), Term] => (# (Zero N) (Zero Term))
+            [(One N), Term] => (# (One N) (Zero Term))
+            [(Two N), Term] => (# (Two N) (Zero Term))
+        }
+        forall (M: Nat, N: Nat) {
+            [(Zero M), (Zero N)] => (@NatTriple (# M N))
+            [(Zero M), (One N)] => (One (# M N))
+            [(Zero M), (Two N)] => (Two (# M N))
+            [(One M), (Zero N)] => (One (# M N))
+            [(One M), (One N)] => (Two (# M N))
+            [(One M), (Two N)] => (@NatTriple (# M N))
+            [(Two M), (Zero N)] => (Two (# M N))
+            [(Two M), (One N)] => (@NatTriple (# M N))
+            [(Two M), (Two N)] => (One (# M N))
+        }
+        forall (Y: Nat, U: NatError) {
+            [U, Y] => U
+        }
+        forall (U: NatError) {
+            [Term, U] => U
+        }
+        forall (N: Nat, U: NatError) {
+            [(Zero N), U] => U
+            [(One N), U] => U
+            [(Two N), U] => U
+        }
+    }
+
+    /// Mask a `Nat` by a digit pattern: each trit of `N` is kept where the corresponding trit of
+    /// `M` is nonzero, and cleared where it is zero. Used as `TritMask<N, M>` or
+    /// `<N as NatTritMask<M>>::Output`. The shorter `Nat` is padded with zero trits, and the
+    /// result is canonical.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `TritMask<{Self}, {B}>`",
+        label = "`TritMask` is not implemented for these arguments",
+        note = "`TritMask` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatTritMask<{B}>` to the `where` clause"
+    )]
+    (TritMask) NatTritMask(Nat, Nat): Nat {
+        [Term, Term] => Term
+        forall (N: Nat) {
+            [Term, (Zero N)] => (# (Zero Term) (Zero N))
+            [Term, (One N)] => (# (Zero Term) (One N))
+            [Term, (Two N)] => (# (Zero Term) (Two N))
+            [(Zero N), Term] => (# (Zero N) (Zero Term))
+            [(One N), Term] => (# (One N) (Zero Term))
+            [(Two N), Term] => (# (Two N) (Zero Term))
+        }
+        forall (M: Nat, N: Nat) {
+            [(Zero M), (Zero N)] => (@NatTriple (# M N))
+            [(Zero M), (One N)] => (@NatTriple (# M N))
+            [(Zero M), (Two N)] => (@NatTriple (# M N))
+            [(One M), (Zero N)] => (@NatTriple (# M N))
+            [(One M), (One N)] => (One (# M N))
+            [(One M), (Two N)] => (One (# M N))
+            [(Two M), (Zero N)] => (@NatTriple (# M N))
+            [(Two M), (One N)] => (Two (# M N))
+            [(Two M), (Two N)] => (Two (# M N))
+        }
+        forall (Y: Nat, U: NatError) {
+            [U, Y] => U
+        }
+        forall (U: NatError) {
+            [Term, U] => U
+        }
+        forall (N: Nat, U: NatError) {
+            [(Zero N), U] => U
+            [(One N), U] => U
+            [(Two N), U] => U
+        }
+    }
+
+    /// The trit of a `Nat` at index `I`, counting from zero at the least significant trit, as one
+    /// of `U0`, `U1` or `U2`. Used as `TestTrit<N, I>` or `<N as NatTestTrit<I>>::Output`. Trits
+    /// past the most significant one are zero.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `TestTrit<{Self}, {B}>`",
+        label = "`TestTrit` is not implemented for these arguments",
+        note = "`TestTrit` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatTestTrit<{B}>` to the `where` clause"
+    )]
+    (TestTrit) NatTestTrit(Nat, Nat): Nat {
+        forall (N: Nat, I: Nat) {
+            [N, I] => (@NatLowTrits (@NatShiftRight N I) One)
+        }
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ternary_trit_len() {
+        assert_eq!(<TritLen<U0> as Nat>::reify(), 0);
+        assert_eq!(<TritLen<U1> as Nat>::reify(), 1);
+        assert_eq!(<TritLen<U2> as Nat>::reify(), 1);
+        assert_eq!(<TritLen<U3> as Nat>::reify(), 2);
+        assert_eq!(<TritLen<U8> as Nat>::reify(), 2);
+        assert_eq!(<TritLen<U9> as Nat>::reify(), 3);
+        assert_eq!(<TritLen<U81> as Nat>::reify(), 5);
+        assert_eq!(<::strong::ternary::TritLen<U9> as Nat>::reify(), 3);
+    }
+
+    #[test]
+    fn ternary_digit_sum() {
+        assert_eq!(<DigitSum<U0> as Nat>::reify(), 0);
+        assert_eq!(<DigitSum<U2> as Nat>::reify(), 2);
+        assert_eq!(<DigitSum<U8> as Nat>::reify(), 4);
+        assert_eq!(<DigitSum<U9> as Nat>::reify(), 1);
+        assert_eq!(<DigitSum<U17> as Nat>::reify(), 5);
+        assert_eq!(<DigitSum<U26> as Nat>::reify(), 6);
+    }
+
+    #[test]
+    fn ternary_count_digit() {
+        assert_eq!(<CountDigit<U0, U0> as Nat>::reify(), 0);
+        assert_eq!(<CountDigit<U9, U0> as Nat>::reify(), 2);
+        assert_eq!(<CountDigit<U9, U1> as Nat>::reify(), 1);
+        assert_eq!(<CountDigit<U9, U2> as Nat>::reify(), 0);
+        assert_eq!(<CountDigit<U17, U0> as Nat>::reify(), 0);
+        assert_eq!(<CountDigit<U17, U1> as Nat>::reify(), 1);
+        assert_eq!(<CountDigit<U17, U2> as Nat>::reify(), 2);
+        assert_eq!(<CountDigit<U26, U2> as Nat>::reify(), 3);
+    }
+
+    #[test]
+    fn ternary_trailing_zeros() {
+        assert_eq!(<TrailingZeros<U1> as Nat>::reify(), 0);
+        assert_eq!(<TrailingZeros<U3> as Nat>::reify(), 1);
+        assert_eq!(<TrailingZeros<U18> as Nat>::reify(), 2);
+        assert_eq!(<TrailingZeros<U25> as Nat>::reify(), 0);
+        assert_eq!(<TrailingZeros<U81> as Nat>::reify(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ternary_trailing_zeros_undefined() {
+        let _: Undefined = <TrailingZeros<U0>>::default();
+        let _ = <TrailingZeros<U0> as Nat>::reify();
+    }
+
+    #[test]
+    fn ternary_most_significant_trit() {
+        assert_eq!(<MostSignificantTrit<U0> as Nat>::reify(), 0);
+        assert_eq!(<MostSignificantTrit<U1> as Nat>::reify(), 1);
+        assert_eq!(<MostSignificantTrit<U5> as Nat>::reify(), 1);
+        assert_eq!(<MostSignificantTrit<U8> as Nat>::reify(), 2);
+        assert_eq!(<MostSignificantTrit<U18> as Nat>::reify(), 2);
+        assert_eq!(<MostSignificantTrit<U27> as Nat>::reify(), 1);
+    }
+
+    #[test]
+    fn ternary_trit_and_or() {
+        assert_eq!(<TritAnd<U5, U7> as Nat>::reify(), 4);
+        assert_eq!(<TritAnd<U17, U4> as Nat>::reify(), 4);
+        assert_eq!(<TritAnd<U80, U13> as Nat>::reify(), 13);
+        assert_eq!(<TritOr<U5, U7> as Nat>::reify(), 8);
+        assert_eq!(<TritOr<U17, U4> as Nat>::reify(), 17);
+        assert_eq!(<TritOr<U0, U8> as Nat>::reify(), 8);
+        let _: U0 = <TritAnd<U0, U8>>::default();
+        let _: U8 = <TritOr<U8, U8>>::default();
+    }
+
+    #[test]
+    fn ternary_trit_xor_mask() {
+        assert_eq!(<TritXor<U17, U4> as Nat>::reify(), 9);
+        assert_eq!(<TritXor<U26, U9> as Nat>::reify(), 8);
+        assert_eq!(<TritXor<U80, U13> as Nat>::reify(), 54);
+        assert_eq!(<TritXor<U8, U8> as Nat>::reify(), 4);
+        assert_eq!(<TritMask<U17, U4> as Nat>::reify(), 8);
+        assert_eq!(<TritMask<U26, U9> as Nat>::reify(), 18);
+        assert_eq!(<TritMask<U80, U13> as Nat>::reify(), 26);
+        let _: U0 = <TritXor<U5, U7>>::default();
+        let _: U0 = <TritMask<U0, U8>>::default();
+    }
+
+    #[test]
+    fn ternary_test_trit() {
+        assert_eq!(<TestTrit<U0, U0> as Nat>::reify(), 0);
+        assert_eq!(<TestTrit<U5, U0> as Nat>::reify(), 2);
+        assert_eq!(<TestTrit<U5, U1> as Nat>::reify(), 1);
+        assert_eq!(<TestTrit<U5, U2> as Nat>::reify(), 0);
+        assert_eq!(<TestTrit<U18, U2> as Nat>::reify(), 2);
+        assert_eq!(<TestTrit<U18, U9> as Nat>::reify(), 0);
+    }
+}
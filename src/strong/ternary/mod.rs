@@ -0,0 +1,1373 @@
+//! Type-level ternary operations, with "strongly" enforced validity. In this module
+//! documentation, unsigned ternary numbers will be referred to as `Nat`s (because they're a
+//! representation of the natural numbers), "natural numbers" (see preceding), or "unsigned
+//! numbers".
+//!
+//! For more information, see the documentation for the `Nat` trait.
+
+pub use types::ternary::*;
+
+use strong::boolean::{Bool, True, False};
+use strong::ordering::{Ordering, Less, Equal, Greater};
+
+pub mod number_theory;
+pub mod combinatorics;
+pub mod digits;
+
+pub use self::number_theory::*;
+pub use self::combinatorics::*;
+pub use self::digits::*;
+
+type_operators! {
+    [A, B, C, D, E, F, G, H]
+
+    /// Project the first value in a `NatPair`. Used as `Nat2First<P>` or `<P as Nat2P1>::Output`;
+    /// for example, `Nat2First<DivRem<X, Y>>` is the quotient of `X` by `Y`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Nat2First<{Self}>`",
+        label = "`Nat2First` is not implemented for these arguments",
+        note = "`Nat2First` takes a `NatPair`",
+        note = "in generic code, add `{Self}: Nat2P1` to the `where` clause"
+    )]
+    (Nat2First) Nat2P1(NatPair): Nat {
+        forall (A: Nat, B: Nat) {
+            [(Nat2 A B)] => A
+        }
+    }
+
+    /// Project the second value in a `NatPair`. Used as `Nat2Second<P>` or
+    /// `<P as Nat2P2>::Output`; for example, `Nat2Second<DivRem<X, Y>>` is the remainder of `X`
+    /// divided by `Y`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Nat2Second<{Self}>`",
+        label = "`Nat2Second` is not implemented for these arguments",
+        note = "`Nat2Second` takes a `NatPair`",
+        note = "in generic code, add `{Self}: Nat2P2` to the `where` clause"
+    )]
+    (Nat2Second) Nat2P2(NatPair): Nat {
+        forall (A: Nat, B: Nat) {
+            [(Nat2 A B)] => B
+        }
+    }
+
+    /// Swap the two values in a `NatPair`. Used as `Nat2Swap<P>` or `<P as Nat2Sw>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Nat2Swap<{Self}>`",
+        label = "`Nat2Swap` is not implemented for these arguments",
+        note = "`Nat2Swap` takes a `NatPair`",
+        note = "in generic code, add `{Self}: Nat2Sw` to the `where` clause"
+    )]
+    (Nat2Swap) Nat2Sw(NatPair): NatPair {
+        forall (A: Nat, B: Nat) {
+            [(Nat2 A B)] => (Nat2 B A)
+        }
+    }
+
+    /// The `Succ` operator adds one to a `Nat`. It is always defined. It can be used as `Succ<X>`
+    /// or `<X as NatSucc>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Succ<{Self}>`",
+        label = "`Succ` is not implemented for these arguments",
+        note = "`Succ` takes a `Nat`",
+        note = "in generic code, add `{Self}: NatSucc` to the `where` clause"
+    )]
+    (Succ) NatSucc(Nat): Nat {
+        [Term] => One
+        forall (X: Nat) {
+            [(Zero X)] => (One X)
+            [(One X)] => (Two X)
+            [(Two X)] => (Zero (# X))
+        }
+        forall (U: NatError) {
+            [U] => U
+        }
+    }
+
+    /// The `Pred` operator subtracts one from a `Nat`. It is defined for non-zero `Nat`s, and can
+    /// be used as `Pred<X>` or `<X as NatPred>::Output`. `Pred<U0>` is `Underflow<U0, U1>`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Pred<{Self}>`",
+        label = "`Pred` is not implemented for these arguments",
+        note = "`Pred` takes a `Nat`",
+        note = "`Pred<U0>` is the error `Underflow<U0, U1>`, which later operators pass along and \
+            which panics when reified; a `Defined` bound on the result turns it into a compile \
+            error",
+        note = "a zero written with a redundant leading `Zero`, such as `Zero<Term>`, is not \
+            recognised as zero, so its underflow ends up nested inside the result; `Normalize` \
+            hand-written arguments first",
+        note = "in generic code, add `{Self}: NatPred` to the `where` clause"
+    )]
+    (Pred) NatPred(Nat): Nat {
+        [Term] => (Underflow Term One)
+        forall (X: Nat) {
+            [(Zero X)] => (Two (# X))
+            [(One X)] => (@NatTriple X)
+            [(Two X)] => (One X)
+        }
+        forall (U: NatError) {
+            [U] => U
+        }
+    }
+
+    /// The `DoublePred` operator subtracts two from a `Nat`. It is defined for `Nat`s greater
+    /// than one, and can be used as `DoublePred<X>` or `<X as NatDoublePred>::Output`. Smaller
+    /// `Nat`s underflow, so that `DoublePred<U1> = Underflow<U1, U2>`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `DoublePred<{Self}>`",
+        label = "`DoublePred` is not implemented for these arguments",
+        note = "`DoublePred` takes a `Nat`",
+        note = "`DoublePred` of `U0` or `U1` is an `Underflow` error rather than a `Nat` value; \
+            `SaturatingSub<{Self}, U2>` stops at zero instead",
+        note = "in generic code, add `{Self}: NatDoublePred` to the `where` clause"
+    )]
+    (DoublePred) NatDoublePred(Nat): Nat {
+        [Term] => (Underflow Term Two)
+        [One] => (Underflow One Two)
+        forall (X: Nat) {
+            [(Zero X)] => (One (@NatPred X))
+            [(One (Zero X))] => (Two (@NatPred (Zero X)))
+            [(One (One X))] => (Two (@NatPred (One X)))
+            [(One (Two X))] => (Two (@NatPred (Two X)))
+            [(Two X)] => (@NatTriple X)
+        }
+        forall (U: NatError) {
+            [U] => U
+        }
+    }
+
+    /// The `Triple` operator triples a `Nat`, and avoids a single level of redundant zeroes
+    /// (and thus helps to preserve unique representations.) It is equivalent to `Zero<N>` unless
+    /// `N` is `Term`, in which case `Triple<Term> = Term`. Its collapsing acts at only one level,
+    /// which should be sufficient unless multiple levels of redundant zeroes are introduced by
+    /// user error. It also propagates undefined values: `Triple<Undefined> = Undefined`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Triple<{Self}>`",
+        label = "`Triple` is not implemented for these arguments",
+        note = "`Triple` takes a `Nat`",
+        note = "in generic code, add `{Self}: NatTriple` to the `where` clause"
+    )]
+    (Triple) NatTriple(Nat): Nat {
+        [Term] => Term
+        forall (N: Nat) {
+            [(Zero N)] => (Zero (Zero N))
+            [(One N)] => (Zero (One N))
+            [(Two N)] => (Zero (Two N))
+        }
+        forall (U: NatError) {
+            [U] => U
+        }
+    }
+
+    /// The `Unique` operator collapses one level of redundant zeroes in a ternary representation.
+    /// Using `Unique` every time we might get a redundant zero *should* get rid of any potential
+    /// problems with non-unique representations of zero.
+    (Unique) NatUnique(Nat): Nat {
+        [Term] => Term
+        [(Zero Term)] => Term
+        forall (N: Nat) {
+            [(Zero (Zero N))] => (Zero (# (Zero N)))
+            [(Zero (One N))] => (Zero (One (# N)))
+            [(Zero (Two N))] => (Zero (Two (# N)))
+            [(One N)] => (One N)
+            [(Two N)] => (Two N)
+        }
+        forall (U: NatError) {
+            [U] => U
+        }
+    }
+
+    /// Bring a `Nat` into canonical form, removing any number of redundant leading zeroes. Used
+    /// as `Normalize<X>` or `<X as NatNormalize>::Output`. Unlike `Unique`, this walks the whole
+    /// `Nat`, so `Normalize<Zero<Zero<Term>>> = Term`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Normalize<{Self}>`",
+        label = "`Normalize` is not implemented for these arguments",
+        note = "`Normalize` takes a `Nat`",
+        note = "in generic code, add `{Self}: NatNormalize` to the `where` clause"
+    )]
+    (Normalize) NatNormalize(Nat): Nat {
+        [Term] => Term
+        forall (N: Nat) {
+            [(Zero N)] => (@NatTriple (# N))
+            [(One N)] => (One (# N))
+            [(Two N)] => (Two (# N))
+        }
+        forall (U: NatError) {
+            [U] => U
+        }
+    }
+
+    /// Whether a `Nat` is in canonical form, with no redundant leading zeroes. Used as
+    /// `IsCanonical<X>` or `<X as NatIsCanonical>::Output`. `Undefined`, and anything containing
+    /// it, is not canonical.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IsCanonical<{Self}>`",
+        label = "`IsCanonical` is not implemented for these arguments",
+        note = "`IsCanonical` takes a `Nat`",
+        note = "in generic code, add `{Self}: NatIsCanonical` to the `where` clause"
+    )]
+    (IsCanonical) NatIsCanonical(Nat): Bool {
+        [Term] => True
+        [(Zero Term)] => False
+        forall (N: Nat) {
+            [(Zero (Zero N))] => (# (Zero N))
+            [(Zero (One N))] => (# (One N))
+            [(Zero (Two N))] => (# (Two N))
+            [(One N)] => (# N)
+            [(Two N)] => (# N)
+        }
+        forall (U: NatError) {
+            [U] => False
+            [(Zero U)] => False
+        }
+    }
+
+    /// Whether a `Nat` is defined; that is, whether it contains no errors such as `Undefined`.
+    /// Used as `IsDefined<X>` or `<X as NatIsDefined>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IsDefined<{Self}>`",
+        label = "`IsDefined` is not implemented for these arguments",
+        note = "`IsDefined` takes a `Nat`",
+        note = "in generic code, add `{Self}: NatIsDefined` to the `where` clause"
+    )]
+    (IsDefined) NatIsDefined(Nat): Bool {
+        [Term] => True
+        forall (N: Nat) {
+            [(Zero N)] => (# N)
+            [(One N)] => (# N)
+            [(Two N)] => (# N)
+        }
+        forall (U: NatError) {
+            [U] => False
+        }
+    }
+
+    /// Replace an undefined `Nat` with a default. Used as `UnwrapOr<X, D>` or
+    /// `<X as NatUnwrapOr<D>>::Output`. The result is `X` if `X` is defined, and `D` otherwise.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `UnwrapOr<{Self}, {B}>`",
+        label = "`UnwrapOr` is not implemented for these arguments",
+        note = "`UnwrapOr` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatUnwrapOr<{B}>` to the `where` clause"
+    )]
+    (UnwrapOr) NatUnwrapOr(Nat, Nat): Nat {
+        forall (X: Nat, D: Nat) {
+            [X, D] => (@NatUnwrapOrInternal (@NatIsDefined X) X D)
+        }
+    }
+
+    (UnwrapOrInternal) NatUnwrapOrInternal(Bool, Nat, Nat): Nat {
+        forall (X: Nat, D: Nat) {
+            [True, X, D] => X
+            [False, X, D] => D
+        }
+    }
+
+    /// `Nat` addition. Used as `Add<X, Y>` or `<X as NatAdd<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Add<{Self}, {B}>`",
+        label = "`Add` is not implemented for these arguments",
+        note = "`Add` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatAdd<{B}>` to the `where` clause"
+    )]
+    (Add) NatAdd(Nat, Nat): Nat {
+        [Term, Term] => Term
+        forall (X: Nat) {
+            [Term, (Zero X)] => (@NatTriple X)
+            [Term, (One X)] => (One X)
+            [Term, (Two X)] => (Two X)
+            [(Zero X), Term] => (@NatTriple X)
+            [(One X), Term] => (One X)
+            [(Two X), Term] => (Two X)
+        }
+        forall (X: Nat, Y: Nat) {
+            [(Zero X), (Zero Y)] => (@NatTriple (# X Y))
+            [(Zero X), (One Y)] => (One (# X Y))
+            [(Zero X), (Two Y)] => (Two (# X Y))
+            [(One X), (Zero Y)] => (One (# X Y))
+            [(One X), (One Y)] => (Two (# X Y))
+            [(One X), (Two Y)] => (Zero (@NatSucc (# X Y)))
+            [(Two X), (Zero Y)] => (Two (# X Y))
+            [(Two X), (One Y)] => (Zero (@NatSucc (# X Y)))
+            [(Two X), (Two Y)] => (One (@NatSucc (# X Y)))
+        }
+        forall (Y: Nat, U: NatError) {
+            [U, Y] => U
+        }
+        forall (U: NatError) {
+            [Term, U] => U
+        }
+        forall (X: Nat, U: NatError) {
+            [(Zero X), U] => U
+            [(One X), U] => U
+            [(Two X), U] => U
+        }
+    }
+
+    /// A convenience operator for propagating undefined values.
+    (TriplePlusOne) NatTriplePlusOne(Nat): Nat {
+        [Term] => One
+        forall (X: Nat) {
+            [(Zero X)] => (One (Zero X))
+            [(One X)] => (One (One X))
+            [(Two X)] => (One (Two X))
+        }
+        forall (U: NatError) {
+            [U] => U
+        }
+    }
+
+    /// A convenience operator for propagating undefined values.
+    (TriplePlusTwo) NatTriplePlusTwo(Nat): Nat {
+        [Term] => Two
+        forall (X: Nat) {
+            [(Zero X)] => (Two (Zero X))
+            [(One X)] => (Two (One X))
+            [(Two X)] => (Two (Two X))
+        }
+        forall (U: NatError) {
+            [U] => U
+        }
+    }
+
+    /// `Nat` subtraction. Used as `Sub<X, Y>` or `<X as NatSub<Y>>::Output`. Any inputs which
+    /// would result in an output less than zero give `Underflow<X, Y>`, unless one of them is
+    /// already an error, which is propagated instead. If you need signed numbers, you should
+    /// probably be using a signed representation, such as balanced ternary.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Sub<{Self}, {B}>`",
+        label = "`Sub` is not implemented for these arguments",
+        note = "`Sub` takes two `Nat`s",
+        note = "if `{B}` is greater than `{Self}`, the result is the error \
+            `Underflow<{Self}, {B}>`, which panics when reified; `SaturatingSub` stops at zero \
+            instead, and `mixed::Diff` gives a signed `Int`",
+        note = "to reject an underflow at compile time, bound the result with `Defined`",
+        note = "in generic code, add `{Self}: NatSub<{B}>` to the `where` clause"
+    )]
+    (Sub) NatSub(Nat, Nat): Nat {
+        forall (X: Nat, Y: Nat) {
+            [X, Y] => (@NatSubCheck (@NatSubInternal X Y) X Y)
+        }
+    }
+
+    /// Subtraction proper. Underflow anywhere in the recursion surfaces as some error at the top,
+    /// which `Sub` then replaces with the right one; division relies on this as well.
+    (SubInternal) NatSubInternal(Nat, Nat): Nat {
+        [Term, Term] => Term
+        forall (X: Nat) {
+            [Term, (Zero X)] => (# Term X)
+            [Term, (One X)] => Undefined
+            [Term, (Two X)] => Undefined
+            [(Zero X), Term] => (@NatTriple X)
+            [(One X), Term] => (One X)
+            [(Two X), Term] => (Two X)
+        }
+        forall (X: Nat, Y: Nat) {
+            [(Zero X), (Zero Y)] => (@NatTriple (# X Y))
+            [(Zero X), (One Y)] => (@NatTriplePlusTwo (@NatPred (# X Y)))
+            [(Zero X), (Two Y)] => (@NatTriplePlusOne (@NatPred (# X Y)))
+            [(One X), (Zero Y)] => (@NatTriplePlusOne (# X Y))
+            [(One X), (One Y)] => (@NatTriple (# X Y))
+            [(One X), (Two Y)] => (@NatTriplePlusTwo (@NatPred (# X Y)))
+            [(Two X), (Zero Y)] => (@NatTriplePlusTwo (# X Y))
+            [(Two X), (One Y)] => (@NatTriplePlusOne (# X Y))
+            [(Two X), (Two Y)] => (@NatTriple (# X Y))
+        }
+        forall (Y: Nat, U: NatError) {
+            [U, Y] => U
+        }
+        forall (U: NatError) {
+            [Term, U] => U
+        }
+        forall (X: Nat, U: NatError) {
+            [(Zero X), U] => U
+            [(One X), U] => U
+            [(Two X), U] => U
+        }
+    }
+
+    (SubCheck) NatSubCheck(Nat, Nat, Nat): Nat {
+        forall (X: Nat, Y: Nat) {
+            [Term, X, Y] => Term
+        }
+        forall (R: Nat, X: Nat, Y: Nat) {
+            [(Zero R), X, Y] => (Zero R)
+            [(One R), X, Y] => (One R)
+            [(Two R), X, Y] => (Two R)
+        }
+        forall (X: Nat, Y: Nat, U: NatError) {
+            [U, X, Y] => (@NatUnderflowOf X Y)
+        }
+    }
+
+    /// The error for an underflowing subtraction `X - Y`: the first of `X` and `Y` which is
+    /// already an error, or else `Underflow<X, Y>`.
+    (UnderflowOf) NatUnderflowOf(Nat, Nat): Nat {
+        forall (Y: Nat) {
+            [Term, Y] => (@NatUnderflowOfRhs Y Term)
+        }
+        forall (X: Nat, Y: Nat) {
+            [(Zero X), Y] => (@NatUnderflowOfRhs Y (Zero X))
+            [(One X), Y] => (@NatUnderflowOfRhs Y (One X))
+            [(Two X), Y] => (@NatUnderflowOfRhs Y (Two X))
+        }
+        forall (Y: Nat, U: NatError) {
+            [U, Y] => U
+        }
+    }
+
+    (UnderflowOfRhs) NatUnderflowOfRhs(Nat, Nat): Nat {
+        forall (X: Nat) {
+            [Term, X] => (Underflow X Term)
+        }
+        forall (Y: Nat, X: Nat) {
+            [(Zero Y), X] => (Underflow X (Zero Y))
+            [(One Y), X] => (Underflow X (One Y))
+            [(Two Y), X] => (Underflow X (Two Y))
+        }
+        forall (X: Nat, U: NatError) {
+            [U, X] => U
+        }
+    }
+
+    /// `Nat` multiplication. Used as `Mul<X, Y>` or `<X as NatMul<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Mul<{Self}, {B}>`",
+        label = "`Mul` is not implemented for these arguments",
+        note = "`Mul` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatMul<{B}>` to the `where` clause"
+    )]
+    (Mul) NatMul(Nat, Nat): Nat {
+        [Term, Term] => Term
+        forall (X: Nat) {
+            [Term, (Zero X)] => Term
+            [Term, (One X)] => Term
+            [Term, (Two X)] => Term
+            [(Zero X), Term] => Term
+            [(One X), Term] => Term
+            [(Two X), Term] => Term
+        }
+        forall (X: Nat, Y: Nat) {
+            [(Zero X), (Zero Y)] => (# X (Zero (Zero Y)))
+            [(Zero X), (One Y)] => (# X (Zero (One Y)))
+            [(Zero X), (Two Y)] => (# X (Zero (Two Y)))
+            [(One X), (Zero Y)] => (@NatAdd (# X (Zero (Zero Y))) (Zero Y))
+            [(One X), (One Y)] => (@NatAdd (# X (Zero (One Y))) (One Y))
+            [(One X), (Two Y)] => (@NatAdd (# X (Zero (Two Y))) (Two Y))
+            [(Two X), (Zero Y)] => (@NatAdd (# (One X) (Zero Y)) (Zero Y))
+            [(Two X), (One Y)] => (@NatAdd (# (One X) (One Y)) (One Y))
+            [(Two X), (Two Y)] => (@NatAdd (# (One X) (Two Y)) (Two Y))
+        }
+        forall (Y: Nat, U: NatError) {
+            [U, Y] => U
+        }
+        forall (U: NatError) {
+            [Term, U] => U
+        }
+        forall (X: Nat, U: NatError) {
+            [(Zero X), U] => U
+            [(One X), U] => U
+            [(Two X), U] => U
+        }
+    }
+
+    /// Drop the least significant trit of a `Nat`, dividing it by three and rounding down. Used as
+    /// `Third<X>` or `<X as NatThird>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Third<{Self}>`",
+        label = "`Third` is not implemented for these arguments",
+        note = "`Third` takes a `Nat`",
+        note = "in generic code, add `{Self}: NatThird` to the `where` clause"
+    )]
+    (Third) NatThird(Nat): Nat {
+        [Term] => Term
+        forall (N: Nat) {
+            [(Zero N)] => N
+            [(One N)] => N
+            [(Two N)] => N
+        }
+        forall (U: NatError) {
+            [U] => U
+        }
+    }
+
+    /// Shift a `Nat` left by `K` trits, multiplying it by `3^K`. Used as `ShiftLeft<N, K>` or
+    /// `<N as NatShiftLeft<K>>::Output`. Shifting zero yields zero, so the result is canonical
+    /// whenever `N` is.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `ShiftLeft<{Self}, {B}>`",
+        label = "`ShiftLeft` is not implemented for these arguments",
+        note = "`ShiftLeft` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatShiftLeft<{B}>` to the `where` clause"
+    )]
+    (ShiftLeft) NatShiftLeft(Nat, Nat): Nat {
+        forall (N: Nat) {
+            [N, Term] => N
+        }
+        forall (N: Nat, K: Nat) {
+            [N, (Zero K)] => (@NatTriple (# N (@NatPred (Zero K))))
+            [N, (One K)] => (@NatTriple (# N (@NatPred (One K))))
+            [N, (Two K)] => (@NatTriple (# N (@NatPred (Two K))))
+        }
+        forall (N: Nat, U: NatError) {
+            [N, U] => U
+        }
+    }
+
+    /// Shift a `Nat` right by `K` trits, dividing it by `3^K` and rounding down. Used as
+    /// `ShiftRight<N, K>` or `<N as NatShiftRight<K>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `ShiftRight<{Self}, {B}>`",
+        label = "`ShiftRight` is not implemented for these arguments",
+        note = "`ShiftRight` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatShiftRight<{B}>` to the `where` clause"
+    )]
+    (ShiftRight) NatShiftRight(Nat, Nat): Nat {
+        forall (N: Nat) {
+            [N, Term] => N
+        }
+        forall (N: Nat, K: Nat) {
+            [N, (Zero K)] => (# (@NatThird N) (@NatPred (Zero K)))
+            [N, (One K)] => (# (@NatThird N) (@NatPred (One K)))
+            [N, (Two K)] => (# (@NatThird N) (@NatPred (Two K)))
+        }
+        forall (N: Nat, U: NatError) {
+            [N, U] => U
+        }
+    }
+
+    /// The lowest `K` trits of a `Nat`; that is, `N` modulo `3^K`. Used as `LowTrits<N, K>` or
+    /// `<N as NatLowTrits<K>>::Output`. Leading zero trits are removed, so the result is
+    /// canonical.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `LowTrits<{Self}, {B}>`",
+        label = "`LowTrits` is not implemented for these arguments",
+        note = "`LowTrits` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatLowTrits<{B}>` to the `where` clause"
+    )]
+    (LowTrits) NatLowTrits(Nat, Nat): Nat {
+        [Term, Term] => Term
+        forall (X: Nat) {
+            [Term, (Zero X)] => Term
+            [Term, (One X)] => Term
+            [Term, (Two X)] => Term
+            [(Zero X), Term] => Term
+            [(One X), Term] => Term
+            [(Two X), Term] => Term
+        }
+        forall (N: Nat, K: Nat) {
+            [(Zero N), (Zero K)] => (@NatTriple (# N (@NatPred (Zero K))))
+            [(Zero N), (One K)] => (@NatTriple (# N (@NatPred (One K))))
+            [(Zero N), (Two K)] => (@NatTriple (# N (@NatPred (Two K))))
+            [(One N), (Zero K)] => (One (# N (@NatPred (Zero K))))
+            [(One N), (One K)] => (One (# N (@NatPred (One K))))
+            [(One N), (Two K)] => (One (# N (@NatPred (Two K))))
+            [(Two N), (Zero K)] => (Two (# N (@NatPred (Zero K))))
+            [(Two N), (One K)] => (Two (# N (@NatPred (One K))))
+            [(Two N), (Two K)] => (Two (# N (@NatPred (Two K))))
+        }
+        forall (Y: Nat, U: NatError) {
+            [U, Y] => U
+        }
+        forall (U: NatError) {
+            [Term, U] => U
+        }
+        forall (X: Nat, U: NatError) {
+            [(Zero X), U] => U
+            [(One X), U] => U
+            [(Two X), U] => U
+        }
+    }
+
+    /// A `Nat` with its lowest `K` trits cleared; that is, `N` rounded down to a multiple of
+    /// `3^K`. Used as `HighTrits<N, K>` or `<N as NatHighTrits<K>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `HighTrits<{Self}, {B}>`",
+        label = "`HighTrits` is not implemented for these arguments",
+        note = "`HighTrits` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatHighTrits<{B}>` to the `where` clause"
+    )]
+    (HighTrits) NatHighTrits(Nat, Nat): Nat {
+        forall (N: Nat, K: Nat) {
+            [N, K] => (@NatShiftLeft (@NatShiftRight N K) K)
+        }
+    }
+
+    /// `Nat` comparison. If the first argument is less than the second, return the third argument;
+    /// else if the first argument is equal to the second, then return the fourth argument; else,
+    /// return the fifth argument. If either of the compared arguments is undefined, then so is the
+    /// result.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Cmp<{Self}, {B}, {C}, {D}, {E}>`",
+        label = "`Cmp` is not implemented for these arguments",
+        note = "`Cmp` takes five `Nat`s",
+        note = "in generic code, add `{Self}: NatCmp<{B}, {C}, {D}, {E}>` to the `where` clause"
+    )]
+    (Cmp) NatCmp(Nat, Nat, Nat, Nat, Nat): Nat {
+        forall (L: Nat, E: Nat, G: Nat) {
+            [Term, Term, L, E, G] => E
+        }
+        forall (X: Nat, L: Nat, E: Nat, G: Nat) {
+            [Term, (Zero X), L, E, G] => (# Term X L E G)
+            [Term, (One X), L, E, G] => L
+            [Term, (Two X), L, E, G] => L
+            [(Zero X), Term, L, E, G] => (# X Term L E G)
+            [(One X), Term, L, E, G] => G
+            [(Two X), Term, L, E, G] => G
+        }
+        forall (X: Nat, Y: Nat, L: Nat, E: Nat, G: Nat) {
+            [(Zero X), (Zero Y), L, E, G] => (# X Y L E G)
+            [(Zero X), (One Y), L, E, G] => (# X Y L L G)
+            [(Zero X), (Two Y), L, E, G] => (# X Y L L G)
+            [(One X), (Zero Y), L, E, G] => (# X Y L G G)
+            [(One X), (One Y), L, E, G] => (# X Y L E G)
+            [(One X), (Two Y), L, E, G] => (# X Y L L G)
+            [(Two X), (Zero Y), L, E, G] => (# X Y L G G)
+            [(Two X), (One Y), L, E, G] => (# X Y L G G)
+            [(Two X), (Two Y), L, E, G] => (# X Y L E G)
+        }
+        forall (Y: Nat, L: Nat, E: Nat, G: Nat, U: NatError) {
+            [U, Y, L, E, G] => U
+        }
+        forall (L: Nat, E: Nat, G: Nat, U: NatError) {
+            [Term, U, L, E, G] => U
+        }
+        forall (X: Nat, L: Nat, E: Nat, G: Nat, U: NatError) {
+            [(Zero X), U, L, E, G] => U
+            [(One X), U, L, E, G] => U
+            [(Two X), U, L, E, G] => U
+        }
+    }
+
+    /// Three-way `Nat` comparison, producing an `Ordering`. Used as `Compare<X, Y>` or
+    /// `<X as NatCompare<Y>>::Output`. Unlike `Cmp`, the result can be used to select between
+    /// types of any kind with `Match`. Not implemented for undefined arguments.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Compare<{Self}, {B}>`",
+        label = "`Compare` is not implemented for these arguments",
+        note = "`Compare` takes two `Nat`s",
+        note = "`Compare` has no result for an error such as `Underflow<L, R>` or `DivByZero`; \
+            `Cmp` passes errors along instead, and `IsDefined` tests for them",
+        note = "in generic code, add `{Self}: NatCompare<{B}>` to the `where` clause"
+    )]
+    (Compare) NatCompare(Nat, Nat): Ordering {
+        forall (X: Nat, Y: Nat) {
+            [X, Y] => (@NatCompareInternal X Y Equal)
+        }
+    }
+
+    /// Compare two `Nat`s trit by trit, from least to most significant. The third argument is the
+    /// ordering of the trits seen so far, which holds unless a more significant trit differs.
+    (CompareInternal) NatCompareInternal(Nat, Nat, Ordering): Ordering {
+        forall (O: Ordering) {
+            [Term, Term, O] => O
+        }
+        forall (X: Nat, O: Ordering) {
+            [Term, (Zero X), O] => (# Term X O)
+            [Term, (One X), O] => Less
+            [Term, (Two X), O] => Less
+            [(Zero X), Term, O] => (# X Term O)
+            [(One X), Term, O] => Greater
+            [(Two X), Term, O] => Greater
+        }
+        forall (X: Nat, Y: Nat, O: Ordering) {
+            [(Zero X), (Zero Y), O] => (# X Y O)
+            [(Zero X), (One Y), O] => (# X Y Less)
+            [(Zero X), (Two Y), O] => (# X Y Less)
+            [(One X), (Zero Y), O] => (# X Y Greater)
+            [(One X), (One Y), O] => (# X Y O)
+            [(One X), (Two Y), O] => (# X Y Less)
+            [(Two X), (Zero Y), O] => (# X Y Greater)
+            [(Two X), (One Y), O] => (# X Y Greater)
+            [(Two X), (Two Y), O] => (# X Y O)
+        }
+    }
+
+    /// The lesser of two `Nat`s. Used as `Min<X, Y>` or `<X as NatMin<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Min<{Self}, {B}>`",
+        label = "`Min` is not implemented for these arguments",
+        note = "`Min` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatMin<{B}>` to the `where` clause"
+    )]
+    (Min) NatMin(Nat, Nat): Nat {
+        forall (X: Nat, Y: Nat) {
+            [X, Y] => (@NatCmp X Y X X Y)
+        }
+    }
+
+    /// The greater of two `Nat`s. Used as `Max<X, Y>` or `<X as NatMax<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Max<{Self}, {B}>`",
+        label = "`Max` is not implemented for these arguments",
+        note = "`Max` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatMax<{B}>` to the `where` clause"
+    )]
+    (Max) NatMax(Nat, Nat): Nat {
+        forall (X: Nat, Y: Nat) {
+            [X, Y] => (@NatCmp X Y Y Y X)
+        }
+    }
+
+    /// Restrict a `Nat` to an interval. Used as `Clamp<X, Lo, Hi>` or
+    /// `<X as NatClamp<Lo, Hi>>::Output`. Returns `Lo` if `X` is less than `Lo`, `Hi` if `X` is
+    /// greater than `Hi`, and `X` otherwise. Undefined if `Lo` is greater than `Hi`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Clamp<{Self}, {B}, {C}>`",
+        label = "`Clamp` is not implemented for these arguments",
+        note = "`Clamp` takes three `Nat`s",
+        note = "if `{B}` is greater than `{C}`, the interval is empty and the result is \
+            `Undefined`",
+        note = "in generic code, add `{Self}: NatClamp<{B}, {C}>` to the `where` clause"
+    )]
+    (Clamp) NatClamp(Nat, Nat, Nat): Nat {
+        forall (X: Nat, Lo: Nat, Hi: Nat) {
+            [X, Lo, Hi] => (@NatCmp Lo Hi
+                (@NatMin (@NatMax X Lo) Hi)
+                (@NatMin (@NatMax X Lo) Hi)
+                Undefined)
+        }
+    }
+
+    /// `Nat` subtraction which saturates at zero instead of underflowing, like
+    /// `usize::saturating_sub`. Used as `SaturatingSub<X, Y>` or
+    /// `<X as NatSaturatingSub<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `SaturatingSub<{Self}, {B}>`",
+        label = "`SaturatingSub` is not implemented for these arguments",
+        note = "`SaturatingSub` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatSaturatingSub<{B}>` to the `where` clause"
+    )]
+    (SaturatingSub) NatSaturatingSub(Nat, Nat): Nat {
+        forall (X: Nat, Y: Nat) {
+            [X, Y] => (@NatCmp X Y Term Term (@NatSub X Y))
+        }
+    }
+
+    /// The absolute difference of two `Nat`s, like `usize::abs_diff`. Used as `AbsDiff<X, Y>` or
+    /// `<X as NatAbsDiff<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `AbsDiff<{Self}, {B}>`",
+        label = "`AbsDiff` is not implemented for these arguments",
+        note = "`AbsDiff` takes two `Nat`s",
+        note = "in generic code, add `{Self}: NatAbsDiff<{B}>` to the `where` clause"
+    )]
+    (AbsDiff) NatAbsDiff(Nat, Nat): Nat {
+        forall (X: Nat, Y: Nat) {
+            [X, Y] => (@NatCmp X Y (@NatSub Y X) Term (@NatSub X Y))
+        }
+    }
+
+    /// `Nat` addition modulo `M`. Used as `WrappingAdd<X, Y, M>` or
+    /// `<X as NatWrappingAdd<Y, M>>::Output`. For `X` and `Y` below `M = 2^8`, this agrees with
+    /// `u8::wrapping_add`. Returns `DivByZero` if `M` is zero.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `WrappingAdd<{Self}, {B}, {C}>`",
+        label = "`WrappingAdd` is not implemented for these arguments",
+        note = "`WrappingAdd` takes three `Nat`s",
+        note = "the modulus `{C}` must not be zero: `WrappingAdd<{Self}, {B}, U0>` is `DivByZero`",
+        note = "in generic code, add `{Self}: NatWrappingAdd<{B}, {C}>` to the `where` clause"
+    )]
+    (WrappingAdd) NatWrappingAdd(Nat, Nat, Nat): Nat {
+        forall (X: Nat, Y: Nat, M: Nat) {
+            [X, Y, M] => (@NatRem (@NatAdd X Y) M)
+        }
+    }
+
+    /// `Nat` subtraction modulo `M`, which wraps around instead of underflowing. Used as
+    /// `WrappingSub<X, Y, M>` or `<X as NatWrappingSub<Y, M>>::Output`. Returns `DivByZero` if
+    /// `M` is zero.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `WrappingSub<{Self}, {B}, {C}>`",
+        label = "`WrappingSub` is not implemented for these arguments",
+        note = "`WrappingSub` takes three `Nat`s",
+        note = "a zero modulus gives `DivByZero`, since the difference is reduced with `Rem`",
+        note = "in generic code, add `{Self}: NatWrappingSub<{B}, {C}>` to the `where` clause"
+    )]
+    (WrappingSub) NatWrappingSub(Nat, Nat, Nat): Nat {
+        forall (X: Nat, Y: Nat, M: Nat) {
+            [X, Y, M] => (@NatRem (@NatSub (@NatAdd (@NatRem X M) M) (@NatRem Y M)) M)
+        }
+    }
+
+    /// `Nat` multiplication modulo `M`. Used as `WrappingMul<X, Y, M>` or
+    /// `<X as NatWrappingMul<Y, M>>::Output`. Returns `DivByZero` if `M` is zero.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `WrappingMul<{Self}, {B}, {C}>`",
+        label = "`WrappingMul` is not implemented for these arguments",
+        note = "`WrappingMul` takes three `Nat`s",
+        note = "the product is reduced modulo `{C}`, so `{C}` of zero gives `DivByZero`",
+        note = "in generic code, add `{Self}: NatWrappingMul<{B}, {C}>` to the `where` clause"
+    )]
+    (WrappingMul) NatWrappingMul(Nat, Nat, Nat): Nat {
+        forall (X: Nat, Y: Nat, M: Nat) {
+            [X, Y, M] => (@NatRem (@NatMul X Y) M)
+        }
+    }
+
+    /// `Nat` undefined-or with pair - if the first argument is undefined, return the pair passed
+    /// in as the third argument; else, return the first paired with the second argument.
+    (UndefOr2) NatUndefOr2(Nat, Nat, NatPair): NatPair {
+        forall (A: Nat, BC: NatPair) {
+            [Term, A, BC] => (Nat2 Term A)
+        }
+        forall (X: Nat, A: Nat, BC: NatPair) {
+            [(Zero X), A, BC] => (Nat2 (Zero X) A)
+            [(One X), A, BC] => (Nat2 (One X) A)
+            [(Two X), A, BC] => (Nat2 (Two X) A)
+        }
+        forall (A: Nat, BC: NatPair, U: NatError) {
+            [U, A, BC] => BC
+        }
+    }
+
+    /// `Nat` *reversal.* This is probably something you *never* want to do! It reverses all the
+    /// trits of the `Nat`, and then removes any leading zeroes so that the result is canonical;
+    /// for example, `Rev<U3> = U1`. To work with the trits of a number as an explicit list,
+    /// possibly with leading zeroes, see the `TritSeq` kind in the `trits` module.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Rev<{Self}>`",
+        label = "`Rev` is not implemented for these arguments",
+        note = "`Rev` takes a `Nat`",
+        note = "in generic code, add `{Self}: NatRev` to the `where` clause"
+    )]
+    (Rev) NatRev(Nat): Nat {
+        forall (N: Nat) {
+            [N] => (@NatNormalize (@NatRevInternal N Term))
+        }
+    }
+
+    /// Reverse the trits of the first argument onto the front of the second. The result keeps
+    /// any zeroes which were least significant in the original, which is what division needs, but
+    /// is not canonical.
+    (RevInternal) NatRevInternal(Nat, Nat): Nat {
+        forall (N: Nat) {
+            [Term, N] => N
+        }
+        forall (M: Nat, N: Nat) {
+            [(Zero M), N] => (# M (Zero N))
+            [(One M), N] => (# M (One N))
+            [(Two M), N] => (# M (Two N))
+        }
+    }
+
+    /// `Nat` truncating division. Used as `Div<X, Y>` or `<X as NatDiv<Y>>::Output`. Returns
+    /// `DivByZero` in the case of a division by zero.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Div<{Self}, {B}>`",
+        label = "`Div` is not implemented for these arguments",
+        note = "`Div` takes two `Nat`s",
+        note = "`Div<{Self}, U0>` is the error `DivByZero`; a `Defined` bound on the result \
+            rejects it at compile time",
+        note = "only a canonical `U0` is caught as a zero divisor: a hand-written zero such as \
+            `Zero<Term>` gives a meaningless quotient, so `Normalize` it first",
+        note = "in generic code, add `{Self}: NatDiv<{B}>` to the `where` clause"
+    )]
+    (Div) NatDiv(Nat, Nat): Nat {
+        forall (N: Nat, D: Nat) {
+            [N, D] => (@Nat2P1 (@NatDivRem N D))
+        }
+    }
+
+    /// `Nat` remainder. Used as `Rem<X, Y>` or `<X as NatRem<Y>>::Output`. Returns
+    /// `DivByZero` in the case of a division by zero.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Rem<{Self}, {B}>`",
+        label = "`Rem` is not implemented for these arguments",
+        note = "`Rem` takes two `Nat`s",
+        note = "as with `Div`, a zero divisor gives `DivByZero`, and one written non-canonically, \
+            such as `Zero<Term>`, is not caught at all",
+        note = "in generic code, add `{Self}: NatRem<{B}>` to the `where` clause"
+    )]
+    (Rem) NatRem(Nat, Nat): Nat {
+        forall (N: Nat, D: Nat) {
+            [N, D] => (@Nat2P2 (@NatDivRem N D))
+        }
+    }
+
+    /// `Nat` truncating division with remainder. Used as `DivRem<X, Y>` or
+    /// `<X as NatDivRem<Y>>::Output`. The result is a `NatPair` with the quotient first and the
+    /// remainder second, computed in a single pass; use this instead of `Div` and `Rem` when both
+    /// are needed. Both are `DivByZero` in the case of a division by zero.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `DivRem<{Self}, {B}>`",
+        label = "`DivRem` is not implemented for these arguments",
+        note = "`DivRem` takes two `Nat`s",
+        note = "dividing by `U0` gives `Nat2<DivByZero, DivByZero>`; take the halves apart with \
+            `Nat2First` and `Nat2Second` to bound either with `Defined`",
+        note = "a zero divisor written non-canonically, such as `Zero<Term>`, is not caught; \
+            `Normalize` hand-written divisors",
+        note = "in generic code, add `{Self}: NatDivRem<{B}>` to the `where` clause"
+    )]
+    (DivRem) NatDivRem(Nat, Nat): NatPair {
+        [Term, Term] => (Nat2 DivByZero DivByZero)
+        forall (D: Nat) {
+            [Term, (Zero D)] => (Nat2 Term Term)
+            [Term, (One D)] => (Nat2 Term Term)
+            [Term, (Two D)] => (Nat2 Term Term)
+        }
+        forall (N: Nat) {
+            [(Zero N), Term] => (Nat2 DivByZero DivByZero)
+            [(One N), Term] => (Nat2 DivByZero DivByZero)
+            [(Two N), Term] => (Nat2 DivByZero DivByZero)
+        }
+        forall (N: Nat, D: Nat) {
+            [(Zero N), (Zero D)] => (@Nat2Sw (@NatDivInternal (@NatRevInternal (Zero N) Term) (Zero D) (Nat2 Term Term)))
+            [(Zero N), (One D)] => (@Nat2Sw (@NatDivInternal (@NatRevInternal (Zero N) Term) (One D) (Nat2 Term Term)))
+            [(Zero N), (Two D)] => (@Nat2Sw (@NatDivInternal (@NatRevInternal (Zero N) Term) (Two D) (Nat2 Term Term)))
+            [(One N), (Zero D)] => (@Nat2Sw (@NatDivInternal (@NatRevInternal (One N) Term) (Zero D) (Nat2 Term Term)))
+            [(One N), (One D)] => (@Nat2Sw (@NatDivInternal (@NatRevInternal (One N) Term) (One D) (Nat2 Term Term)))
+            [(One N), (Two D)] => (@Nat2Sw (@NatDivInternal (@NatRevInternal (One N) Term) (Two D) (Nat2 Term Term)))
+            [(Two N), (Zero D)] => (@Nat2Sw (@NatDivInternal (@NatRevInternal (Two N) Term) (Zero D) (Nat2 Term Term)))
+            [(Two N), (One D)] => (@Nat2Sw (@NatDivInternal (@NatRevInternal (Two N) Term) (One D) (Nat2 Term Term)))
+            [(Two N), (Two D)] => (@Nat2Sw (@NatDivInternal (@NatRevInternal (Two N) Term) (Two D) (Nat2 Term Term)))
+        }
+        forall (Y: Nat, U: NatError) {
+            [U, Y] => (Nat2 U U)
+        }
+        forall (U: NatError) {
+            [Term, U] => (Nat2 U U)
+        }
+        forall (N: Nat, U: NatError) {
+            [(Zero N), U] => (Nat2 U U)
+            [(One N), U] => (Nat2 U U)
+            [(Two N), U] => (Nat2 U U)
+        }
+    }
+
+    (DivInternal) NatDivInternal(Nat, Nat, NatPair): NatPair {
+        forall (D: Nat, RQ: NatPair) {
+            [Term, D, RQ] => RQ
+        }
+        forall (N: Nat, D: Nat, R: Nat, Q: Nat) {
+            [(Zero N), D, (Nat2 R Q)] => (# N D
+                (@NatUndefOr2
+                    (@NatSubInternal (@NatSubInternal (@NatTriple R) D) D) (Two Q)
+                    (@NatUndefOr2
+                        (@NatSubInternal (@NatTriple R) D) (One Q)
+                        (Nat2 (@NatTriple R) (@NatTriple Q)))))
+            [(One N), D, (Nat2 R Q)] => (# N D
+                (@NatUndefOr2
+                    (@NatSubInternal (@NatSubInternal (One R) D) D) (Two Q)
+                    (@NatUndefOr2
+                        (@NatSubInternal (One R) D) (One Q)
+                        (Nat2 (One R) (@NatTriple Q)))))
+            [(Two N), D, (Nat2 R Q)] => (# N D
+                (@NatUndefOr2
+                    (@NatSubInternal (@NatSubInternal (Two R) D) D) (Two Q)
+                    (@NatUndefOr2
+                        (@NatSubInternal (Two R) D) (One Q)
+                        (Nat2 (Two R) (@NatTriple Q)))))
+        }
+    }
+}
+
+
+/// A marker trait implemented exactly by the canonical `Nat`s, those with no redundant leading
+/// zeroes. Use it as a bound to reject non-canonical inputs at compile time; see `IsCanonical`
+/// and `Normalize`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a canonical `Nat`",
+    label = "this has redundant leading zeroes",
+    note = "hand-written `Nat`s such as `Zero<Term>` can be put into canonical form with \
+        `Normalize`"
+)]
+pub trait Canonical: Nat {}
+
+impl<N> Canonical for N
+    where N: NatIsCanonical, <N as NatIsCanonical>::Output: CanonicalHolds {}
+
+/// A marker trait implemented exactly by the defined `Nat`s, those which contain no errors. Use it
+/// as a bound to require that the result of an operation such as `Sub` or `Div` is defined, so
+/// that a mistake fails at compile time instead of panicking at `reify`; see `IsDefined`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a defined `Nat`",
+    label = "this contains an error value",
+    note = "`Underflow<L, R>` is the result of a `Sub` or `Pred` below zero, `DivByZero` of a \
+        `Div` or `Rem` by zero, and `Undefined` of any other operation with no result"
+)]
+pub trait Defined: Nat {}
+
+impl<N> Defined for N
+    where N: NatIsDefined, <N as NatIsDefined>::Output: DefinedHolds {}
+
+/// The test underlying `Canonical`, which must come out `True`.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "the `Nat` is not canonical",
+    label = "this has redundant leading zeroes",
+    note = "hand-written `Nat`s such as `Zero<Term>` can be put into canonical form with \
+        `Normalize`"
+)]
+pub trait CanonicalHolds {}
+
+impl CanonicalHolds for True {}
+
+/// The test underlying `Defined`, which must come out `True`.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "the `Nat` is not defined",
+    label = "this contains an error value",
+    note = "`Underflow<L, R>` is the result of a `Sub` or `Pred` below zero, `DivByZero` of a \
+        `Div` or `Rem` by zero, and `Undefined` of any other operation with no result"
+)]
+pub trait DefinedHolds {}
+
+impl DefinedHolds for True {}
+
+
+#[cfg(test)]
+mod tests {
+    use std::cmp;
+
+    use strong::ordering::Match;
+    use super::*;
+
+    #[test]
+    fn ternary_succ() {
+        assert_eq!(<Succ<U0> as Nat>::reify(), 1);
+        assert_eq!(<Succ<U7> as Nat>::reify(), 8);
+        assert_eq!(<Succ<U2> as Nat>::reify(), 3);
+        assert_eq!(<Succ<U9> as Nat>::reify(), 10);
+        assert_eq!(<Succ<U5> as Nat>::reify(), 6);
+        assert_eq!(<Succ<U8> as Nat>::reify(), 9);
+        assert_eq!(<Succ<U3> as Nat>::reify(), 4);
+    }
+
+    #[test]
+    fn ternary_pred() {
+        assert_eq!(<Pred<U7> as Nat>::reify(), 6);
+        assert_eq!(<Pred<U2> as Nat>::reify(), 1);
+        assert_eq!(<Pred<U9> as Nat>::reify(), 8);
+        assert_eq!(<Pred<U5> as Nat>::reify(), 4);
+        assert_eq!(<Pred<U8> as Nat>::reify(), 7);
+        assert_eq!(<Pred<U3> as Nat>::reify(), 2);
+    }
+
+    #[test]
+    fn ternary_double_pred() {
+        assert_eq!(<DoublePred<U7> as Nat>::reify(), 5);
+        assert_eq!(<DoublePred<U2> as Nat>::reify(), 0);
+        assert_eq!(<DoublePred<U9> as Nat>::reify(), 7);
+        assert_eq!(<DoublePred<U5> as Nat>::reify(), 3);
+        assert_eq!(<DoublePred<U8> as Nat>::reify(), 6);
+        assert_eq!(<DoublePred<U3> as Nat>::reify(), 1);
+    }
+
+    #[test]
+    fn ternary_add() {
+        assert_eq!(<Add<U0, U10> as Nat>::reify(), 10);
+        assert_eq!(<Add<U2, U11> as Nat>::reify(), 13);
+        assert_eq!(<Add<U25, U12> as Nat>::reify(), 37);
+        assert_eq!(<Add<U10, U18> as Nat>::reify(), 28);
+        assert_eq!(<Add<U15, U2> as Nat>::reify(), 17);
+        assert_eq!(<Add<U24, U5> as Nat>::reify(), 29);
+        assert_eq!(<Add<U13, U1> as Nat>::reify(), 14);
+        assert_eq!(<Add<U6, U23> as Nat>::reify(), 29);
+        assert_eq!(<Add<U2, U17> as Nat>::reify(), 19);
+        assert_eq!(<Add<U26, U25> as Nat>::reify(), 51);
+    }
+
+    #[test]
+    fn ternary_sub() {
+        assert_eq!(<Sub<U25, U12> as Nat>::reify(), 13);
+        assert_eq!(<Sub<U15, U2> as Nat>::reify(), 13);
+        assert_eq!(<Sub<U24, U5> as Nat>::reify(), 19);
+        assert_eq!(<Sub<U13, U1> as Nat>::reify(), 12);
+        assert_eq!(<Sub<U26, U25> as Nat>::reify(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ternary_sub_undefined() {
+        let _: Underflow<U1, U9> = <Sub<U1, U9>>::default();
+        let _: Underflow<U1, U9> = <Sub<Sub<U1, U9>, U9>>::default();
+        let _: Underflow<U0, U1> = <Pred<U0>>::default();
+        let _: Underflow<U1, U2> = <DoublePred<U1>>::default();
+        let _: DivByZero = <Sub<Div<U1, U0>, Sub<U1, U9>>>::default();
+        let _: Underflow<U1, U9> = <Sub<U3, Sub<U1, U9>>>::default();
+        let _ = <Sub<Sub<U1, U9>, U9> as Nat>::reify();
+    }
+
+    #[test]
+    #[should_panic(expected = "underflowing subtraction 4 - 5")]
+    fn ternary_sub_underflow_message() {
+        let _ = <Sub<U4, U5> as Nat>::reify();
+    }
+
+    #[test]
+    fn ternary_shift() {
+        assert_eq!(<ShiftLeft<U0, U3> as Nat>::reify(), 0);
+        assert_eq!(<ShiftLeft<U5, U0> as Nat>::reify(), 5);
+        assert_eq!(<ShiftLeft<U5, U1> as Nat>::reify(), 15);
+        assert_eq!(<ShiftLeft<U2, U3> as Nat>::reify(), 54);
+        assert_eq!(<ShiftRight<U0, U2> as Nat>::reify(), 0);
+        assert_eq!(<ShiftRight<U5, U0> as Nat>::reify(), 5);
+        assert_eq!(<ShiftRight<U17, U1> as Nat>::reify(), 5);
+        assert_eq!(<ShiftRight<U26, U2> as Nat>::reify(), 2);
+        assert_eq!(<ShiftRight<U26, U4> as Nat>::reify(), 0);
+        assert_eq!(<Third<U8> as Nat>::reify(), 2);
+    }
+
+    #[test]
+    fn ternary_low_high_trits() {
+        assert_eq!(<LowTrits<U0, U2> as Nat>::reify(), 0);
+        assert_eq!(<LowTrits<U17, U0> as Nat>::reify(), 0);
+        assert_eq!(<LowTrits<U17, U1> as Nat>::reify(), 2);
+        assert_eq!(<LowTrits<U17, U2> as Nat>::reify(), 8);
+        assert_eq!(<LowTrits<U17, U5> as Nat>::reify(), 17);
+        assert_eq!(<HighTrits<U17, U0> as Nat>::reify(), 17);
+        assert_eq!(<HighTrits<U17, U1> as Nat>::reify(), 15);
+        assert_eq!(<HighTrits<U17, U2> as Nat>::reify(), 9);
+        assert_eq!(<HighTrits<U17, U3> as Nat>::reify(), 0);
+    }
+
+    #[test]
+    fn ternary_low_trits_canonical() {
+        let _: Term = <LowTrits<U9, U2>>::default();
+        let _: One<Term> = <LowTrits<U10, U2>>::default();
+        let _: Term = <HighTrits<U8, U2>>::default();
+        let _: Zero<Zero<One<Term>>> = <ShiftLeft<U1, U2>>::default();
+    }
+
+    #[test]
+    fn ternary_normalize() {
+        let _: Term = <Normalize<Zero<Zero<Term>>>>::default();
+        let _: One<Term> = <Normalize<One<Zero<Zero<Term>>>>>::default();
+        let _: Zero<Two<Term>> = <Normalize<Zero<Two<Zero<Term>>>>>::default();
+        let _: U17 = <Normalize<U17>>::default();
+        assert_eq!(<Normalize<Two<Zero<Zero<Zero<Term>>>>> as Nat>::reify(), 2);
+    }
+
+    #[test]
+    fn ternary_is_canonical() {
+        assert!(<IsCanonical<U0> as Bool>::reify());
+        assert!(<IsCanonical<U9> as Bool>::reify());
+        assert!(<IsCanonical<U26> as Bool>::reify());
+        assert!(!<IsCanonical<Zero<Term>> as Bool>::reify());
+        assert!(!<IsCanonical<One<Zero<Zero<Term>>>> as Bool>::reify());
+        assert!(!<IsCanonical<Undefined> as Bool>::reify());
+        assert!(!<IsCanonical<Two<Undefined>> as Bool>::reify());
+    }
+
+    #[test]
+    fn ternary_canonical() {
+        fn canonical<N: Canonical>() -> usize {
+            N::reify()
+        }
+
+        assert_eq!(canonical::<U0>(), 0);
+        assert_eq!(canonical::<U18>(), 18);
+        assert_eq!(canonical::<Normalize<Two<Zero<Term>>>>(), 2);
+    }
+
+    #[test]
+    fn ternary_is_defined() {
+        assert!(<IsDefined<U0> as Bool>::reify());
+        assert!(<IsDefined<U26> as Bool>::reify());
+        assert!(<IsDefined<Sub<U9, U4>> as Bool>::reify());
+        assert!(!<IsDefined<Sub<U4, U9>> as Bool>::reify());
+        assert!(!<IsDefined<Div<U4, U0>> as Bool>::reify());
+        assert!(!<IsDefined<Undefined> as Bool>::reify());
+        assert!(!<IsDefined<One<Undefined>> as Bool>::reify());
+    }
+
+    #[test]
+    fn ternary_unwrap_or() {
+        assert_eq!(<UnwrapOr<U5, U7> as Nat>::reify(), 5);
+        assert_eq!(<UnwrapOr<Sub<U5, U7>, U0> as Nat>::reify(), 0);
+        assert_eq!(<UnwrapOr<Div<U5, U0>, U7> as Nat>::reify(), 7);
+        assert_eq!(<UnwrapOr<Zero<Undefined>, U1> as Nat>::reify(), 1);
+    }
+
+    #[test]
+    fn ternary_defined() {
+        fn defined<N: Defined>() -> usize {
+            N::reify()
+        }
+
+        assert_eq!(defined::<U0>(), 0);
+        assert_eq!(defined::<Sub<U18, U5>>(), 13);
+        assert_eq!(defined::<Rem<U18, U5>>(), 3);
+    }
+
+    #[test]
+    fn ternary_rev() {
+        assert_eq!(<Rev<U0> as Nat>::reify(), 0);
+        assert_eq!(<Rev<U5> as Nat>::reify(), 7);
+        assert_eq!(<Rev<U17> as Nat>::reify(), 25);
+        let _: U1 = <Rev<U3>>::default();
+        let _: U2 = <Rev<U18>>::default();
+    }
+
+    #[test]
+    fn ternary_compare() {
+        assert_eq!(<Compare<U0, U0> as Ordering>::reify(), cmp::Ordering::Equal);
+        assert_eq!(<Compare<U0, U1> as Ordering>::reify(), cmp::Ordering::Less);
+        assert_eq!(<Compare<U1, U0> as Ordering>::reify(), cmp::Ordering::Greater);
+        assert_eq!(<Compare<U7, U9> as Ordering>::reify(), cmp::Ordering::Less);
+        assert_eq!(<Compare<U9, U7> as Ordering>::reify(), cmp::Ordering::Greater);
+        assert_eq!(<Compare<U8, U8> as Ordering>::reify(), cmp::Ordering::Equal);
+        assert_eq!(<Compare<U5, U22> as Ordering>::reify(), cmp::Ordering::Less);
+        assert_eq!(<Compare<U26, U25> as Ordering>::reify(), cmp::Ordering::Greater);
+        assert_eq!(<Match<Compare<U3, U4>, U1, U2, U3> as Nat>::reify(), 1);
+        assert_eq!(<Match<Compare<U4, U4>, U1, U2, U3> as Nat>::reify(), 2);
+        assert_eq!(<Match<Compare<U5, U4>, U1, U2, U3> as Nat>::reify(), 3);
+    }
+
+    #[test]
+    fn ternary_min_max() {
+        assert_eq!(<Min<U0, U0> as Nat>::reify(), 0);
+        assert_eq!(<Min<U7, U9> as Nat>::reify(), 7);
+        assert_eq!(<Min<U9, U7> as Nat>::reify(), 7);
+        assert_eq!(<Min<U5, U5> as Nat>::reify(), 5);
+        assert_eq!(<Min<U0, U26> as Nat>::reify(), 0);
+        assert_eq!(<Max<U0, U0> as Nat>::reify(), 0);
+        assert_eq!(<Max<U7, U9> as Nat>::reify(), 9);
+        assert_eq!(<Max<U9, U7> as Nat>::reify(), 9);
+        assert_eq!(<Max<U5, U5> as Nat>::reify(), 5);
+        assert_eq!(<Max<U0, U26> as Nat>::reify(), 26);
+    }
+
+    #[test]
+    fn ternary_clamp() {
+        assert_eq!(<Clamp<U0, U3, U8> as Nat>::reify(), 3);
+        assert_eq!(<Clamp<U3, U3, U8> as Nat>::reify(), 3);
+        assert_eq!(<Clamp<U5, U3, U8> as Nat>::reify(), 5);
+        assert_eq!(<Clamp<U8, U3, U8> as Nat>::reify(), 8);
+        assert_eq!(<Clamp<U26, U3, U8> as Nat>::reify(), 8);
+        assert_eq!(<Clamp<U26, U4, U4> as Nat>::reify(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ternary_min_max_undefined() {
+        let _: Underflow<U1, U2> = <Min<Sub<U1, U2>, U3>>::default();
+        let _: Underflow<U1, U2> = <Max<U3, Sub<U1, U2>>>::default();
+        let _: Underflow<U1, U2> = <Clamp<U3, Sub<U1, U2>, U5>>::default();
+        let _ = <Clamp<U3, U5, U4> as Nat>::reify();
+    }
+
+    #[test]
+    fn ternary_saturating_sub() {
+        assert_eq!(<SaturatingSub<U0, U0> as Nat>::reify(), 0usize.saturating_sub(0));
+        assert_eq!(<SaturatingSub<U9, U4> as Nat>::reify(), 9usize.saturating_sub(4));
+        assert_eq!(<SaturatingSub<U4, U9> as Nat>::reify(), 4usize.saturating_sub(9));
+        assert_eq!(<SaturatingSub<U0, U26> as Nat>::reify(), 0usize.saturating_sub(26));
+        assert_eq!(<SaturatingSub<U26, U26> as Nat>::reify(), 26usize.saturating_sub(26));
+    }
+
+    #[test]
+    fn ternary_abs_diff() {
+        assert_eq!(<AbsDiff<U0, U0> as Nat>::reify(), 0usize.abs_diff(0));
+        assert_eq!(<AbsDiff<U9, U4> as Nat>::reify(), 9usize.abs_diff(4));
+        assert_eq!(<AbsDiff<U4, U9> as Nat>::reify(), 4usize.abs_diff(9));
+        assert_eq!(<AbsDiff<U0, U26> as Nat>::reify(), 0usize.abs_diff(26));
+    }
+
+    #[test]
+    fn ternary_wrapping() {
+        type U256 = One<One<One<Zero<Zero<One<Term>>>>>>;
+
+        assert_eq!(<WrappingAdd<U243, U81, U256> as Nat>::reify(),
+                   243u8.wrapping_add(81) as usize);
+        assert_eq!(<WrappingAdd<U17, U8, U256> as Nat>::reify(), 17u8.wrapping_add(8) as usize);
+        assert_eq!(<WrappingSub<U5, U9, U256> as Nat>::reify(), 5u8.wrapping_sub(9) as usize);
+        assert_eq!(<WrappingSub<U9, U5, U256> as Nat>::reify(), 9u8.wrapping_sub(5) as usize);
+        assert_eq!(<WrappingSub<U0, U243, U256> as Nat>::reify(),
+                   0u8.wrapping_sub(243) as usize);
+        assert_eq!(<WrappingMul<U27, U81, U256> as Nat>::reify(),
+                   27u8.wrapping_mul(81) as usize);
+        assert_eq!(<WrappingMul<U17, U3, U256> as Nat>::reify(), 17u8.wrapping_mul(3) as usize);
+        assert_eq!(<WrappingAdd<U5, U9, U7> as Nat>::reify(), 0);
+        assert_eq!(<WrappingSub<U5, U9, U7> as Nat>::reify(), 3);
+        assert_eq!(<WrappingSub<U26, U9, U7> as Nat>::reify(), 3);
+        assert_eq!(<WrappingMul<U5, U9, U7> as Nat>::reify(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ternary_wrapping_undefined() {
+        let _: DivByZero = <WrappingAdd<U5, U9, U0>>::default();
+        let _: Underflow<U1, U2> = <SaturatingSub<Sub<U1, U2>, U3>>::default();
+        let _: Underflow<U1, U2> = <AbsDiff<U3, Sub<U1, U2>>>::default();
+        let _ = <WrappingSub<U5, U9, U0> as Nat>::reify();
+    }
+
+    #[test]
+    fn ternary_undef_or_2() {
+        assert_eq!(<UndefOr2<Sub<Sub<U1, U9>, U9>, U2, Nat2<U1, U0>> as NatPair>::reify(), (1, 0));
+        assert_eq!(<UndefOr2<Sub<Sub<U2, U1>, U1>, U2, Nat2<U0, U1>> as NatPair>::reify(), (0, 2));
+    }
+
+    #[test]
+    fn ternary_mul() {
+        assert_eq!(<Mul<U0, U0> as Nat>::reify(), 0);
+        assert_eq!(<Mul<U2, U2> as Nat>::reify(), 4);
+        assert_eq!(<Mul<U7, U9> as Nat>::reify(), 63);
+        assert_eq!(<Mul<U2, U1> as Nat>::reify(), 2);
+        assert_eq!(<Mul<U9, U5> as Nat>::reify(), 45);
+        assert_eq!(<Mul<U5, U8> as Nat>::reify(), 40);
+        assert_eq!(<Mul<U8, U2> as Nat>::reify(), 16);
+        assert_eq!(<Mul<U3, U2> as Nat>::reify(), 6);
+    }
+
+    #[test]
+    fn ternary_div() {
+        assert_eq!(<Div<U0, U1> as Nat>::reify(), 0);
+        assert_eq!(<Div<U2, U2> as Nat>::reify(), 1);
+        assert_eq!(<Div<U8, U2> as Nat>::reify(), 4);
+        assert_eq!(<Div<U7, U9> as Nat>::reify(), 0);
+        assert_eq!(<Div<U2, U1> as Nat>::reify(), 2);
+        assert_eq!(<Div<U9, U5> as Nat>::reify(), 1);
+        assert_eq!(<Div<U5, U8> as Nat>::reify(), 0);
+        assert_eq!(<Div<U3, U2> as Nat>::reify(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ternary_div_undefined() {
+        let _: Underflow<U1, U2> = <Div<Sub<U1, U2>, U3>>::default();
+        let _: Underflow<U1, U2> = <Rem<U3, Sub<U1, U2>>>::default();
+        let _: DivByZero = <Div<U3, U0>>::default();
+        let _: DivByZero = <Rem<U0, U0>>::default();
+        let _ = <Div<U3, U0> as Nat>::reify();
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn ternary_div_by_zero_message() {
+        let _ = <Rem<U7, U0> as Nat>::reify();
+    }
+
+    #[test]
+    fn ternary_rem() {
+        assert_eq!(<Rem<U0, U1> as Nat>::reify(), 0);
+        assert_eq!(<Rem<U2, U2> as Nat>::reify(), 0);
+        assert_eq!(<Rem<U8, U2> as Nat>::reify(), 0);
+        assert_eq!(<Rem<U7, U9> as Nat>::reify(), 7);
+        assert_eq!(<Rem<U2, U1> as Nat>::reify(), 0);
+        assert_eq!(<Rem<U9, U5> as Nat>::reify(), 4);
+        assert_eq!(<Rem<U5, U8> as Nat>::reify(), 5);
+        assert_eq!(<Rem<U3, U2> as Nat>::reify(), 1);
+    }
+
+    #[test]
+    fn ternary_div_rem() {
+        assert_eq!(<DivRem<U0, U1> as NatPair>::reify(), (0, 0));
+        assert_eq!(<DivRem<U8, U2> as NatPair>::reify(), (4, 0));
+        assert_eq!(<DivRem<U7, U9> as NatPair>::reify(), (0, 7));
+        assert_eq!(<DivRem<U9, U5> as NatPair>::reify(), (1, 4));
+        assert_eq!(<DivRem<U26, U4> as NatPair>::reify(), (6, 2));
+        assert_eq!(<Nat2First<DivRem<U17, U3>> as Nat>::reify(), 5);
+        assert_eq!(<Nat2Second<DivRem<U17, U3>> as Nat>::reify(), 2);
+        assert_eq!(<Nat2Swap<DivRem<U17, U3>> as NatPair>::reify(), (2, 5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn ternary_div_rem_undefined() {
+        let _: Nat2<DivByZero, DivByZero> = <DivRem<U5, U0>>::default();
+        let _ = <Nat2First<DivRem<U5, U0>> as Nat>::reify();
+    }
+}
@@ -0,0 +1,230 @@
+//! Number-theoretic type operators on `Nat`s: parity, primality testing and factorisation. The
+//! latter two are built on top of `NatRem` by trial division. Trial division is slow, so these
+//! operators are best kept to fairly small numbers.
+
+use strong::boolean::{Bool, BoolNot, True, False, Undefined as BoolUndefined};
+use super::*;
+
+type_operators! {
+    [A, B, C, D, E, F, G, H]
+
+    /// Whether a `Nat` is even. Used as `IsEven<N>` or `<N as NatIsEven>::Output`. Every power of
+    /// three is odd, so a `Nat` is even exactly when it has an even number of `One` trits. The
+    /// parity of a `NatError` is `Undefined`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IsEven<{Self}>`",
+        label = "`IsEven` is not implemented for these arguments",
+        note = "`IsEven` takes a `Nat`",
+        note = "in generic code, add `{Self}: NatIsEven` to the `where` clause"
+    )]
+    (IsEven) NatIsEven(Nat): Bool {
+        [Term] => True
+        forall (N: Nat) {
+            [(Zero N)] => (# N)
+            [(One N)] => (@BoolNot (# N))
+            [(Two N)] => (# N)
+        }
+        forall (U: NatError) {
+            [U] => BoolUndefined
+        }
+    }
+
+    /// Whether a `Nat` is odd. Used as `IsOdd<N>` or `<N as NatIsOdd>::Output`. As with `IsEven`,
+    /// the parity of a `NatError` is `Undefined`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IsOdd<{Self}>`",
+        label = "`IsOdd` is not implemented for these arguments",
+        note = "`IsOdd` takes a `Nat`",
+        note = "in generic code, add `{Self}: NatIsOdd` to the `where` clause"
+    )]
+    (IsOdd) NatIsOdd(Nat): Bool {
+        forall (N: Nat) {
+            [N] => (@BoolNot (@NatIsEven N))
+        }
+    }
+
+    /// The smallest factor of a `Nat` which is greater than one. For a prime `N`, this is `N`
+    /// itself. Used as `SmallestFactor<N>` or `<N as NatSmallestFactor>::Output`. Returns
+    /// `Undefined` for zero and one, which have no such factor.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `SmallestFactor<{Self}>`",
+        label = "`SmallestFactor` is not implemented for these arguments",
+        note = "`SmallestFactor` takes a `Nat`",
+        note = "`U0` and `U1` have no factor greater than one, and give `Undefined`",
+        note = "in generic code, add `{Self}: NatSmallestFactor` to the `where` clause"
+    )]
+    (SmallestFactor) NatSmallestFactor(Nat): Nat {
+        [Term] => Undefined
+        [(One Term)] => Undefined
+        forall (N: Nat) {
+            [(Zero N)] => (@NatSmallestFactorFrom (Zero N) Two)
+            [(One (Zero N))] => (@NatSmallestFactorFrom (One (Zero N)) Two)
+            [(One (One N))] => (@NatSmallestFactorFrom (One (One N)) Two)
+            [(One (Two N))] => (@NatSmallestFactorFrom (One (Two N)) Two)
+            [(Two N)] => (@NatSmallestFactorFrom (Two N) Two)
+        }
+        forall (U: NatError) {
+            [U] => U
+        }
+    }
+
+    /// Trial division of the first argument, starting from the second. If the square of the
+    /// candidate divisor exceeds the number, then the number is prime; otherwise, we test the
+    /// remainder.
+    (SmallestFactorFrom) NatSmallestFactorFrom(Nat, Nat): Nat {
+        forall (N: Nat, D: Nat) {
+            [N, D] => (@NatSmallestFactorCheck (@NatCmp (@NatMul D D) N Term Term One) N D)
+        }
+    }
+
+    (SmallestFactorCheck) NatSmallestFactorCheck(Nat, Nat, Nat): Nat {
+        forall (N: Nat, D: Nat) {
+            [One, N, D] => N
+            [Term, N, D] => (@NatSmallestFactorRem (@NatRem N D) N D)
+        }
+    }
+
+    (SmallestFactorRem) NatSmallestFactorRem(Nat, Nat, Nat): Nat {
+        forall (N: Nat, D: Nat) {
+            [Term, N, D] => D
+        }
+        forall (X: Nat, N: Nat, D: Nat) {
+            [(Zero X), N, D] => (@NatSmallestFactorFrom N (@NatSucc D))
+            [(One X), N, D] => (@NatSmallestFactorFrom N (@NatSucc D))
+            [(Two X), N, D] => (@NatSmallestFactorFrom N (@NatSucc D))
+        }
+    }
+
+    /// Primality testing. Used as `IsPrime<N>` or `<N as NatIsPrime>::Output`. Zero and one are
+    /// not prime, and whether a `NatError` is prime is `Undefined`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `IsPrime<{Self}>`",
+        label = "`IsPrime` is not implemented for these arguments",
+        note = "`IsPrime` takes a `Nat`",
+        note = "in generic code, add `{Self}: NatIsPrime` to the `where` clause"
+    )]
+    (IsPrime) NatIsPrime(Nat): Bool {
+        [Term] => False
+        [(One Term)] => False
+        forall (N: Nat) {
+            [(Zero N)] => (@NatIsPrimeInternal (@NatCmp (@NatSmallestFactor (Zero N)) (Zero N) Term One Term))
+            [(One (Zero N))] => (@NatIsPrimeInternal (@NatCmp (@NatSmallestFactor (One (Zero N))) (One (Zero N)) Term One Term))
+            [(One (One N))] => (@NatIsPrimeInternal (@NatCmp (@NatSmallestFactor (One (One N))) (One (One N)) Term One Term))
+            [(One (Two N))] => (@NatIsPrimeInternal (@NatCmp (@NatSmallestFactor (One (Two N))) (One (Two N)) Term One Term))
+            [(Two N)] => (@NatIsPrimeInternal (@NatCmp (@NatSmallestFactor (Two N)) (Two N) Term One Term))
+        }
+        forall (U: NatError) {
+            [U] => BoolUndefined
+        }
+    }
+
+    (IsPrimeInternal) NatIsPrimeInternal(Nat): Bool {
+        [Term] => False
+        [One] => True
+    }
+
+    /// Prime factorisation. Used as `Factorize<N>` or `<N as NatFactorize>::Output`. Produces a
+    /// `NatList` of the prime factors of `N` in ascending order, with repeated factors repeated.
+    /// The factorisation of one is the empty list; zero has no factorisation, and so
+    /// `Factorize<U0>` is not implemented. A `NatList` has no error value, so neither is
+    /// `Factorize` of a `NatError`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Factorize<{Self}>`",
+        label = "`Factorize` is not implemented for these arguments",
+        note = "`Factorize` takes a `Nat`",
+        note = "`Factorize<U0>` is not implemented, since zero has no factorisation",
+        note = "nor is `Factorize` of an error such as `Underflow<L, R>` or `DivByZero`, which a \
+            `NatList` has no way to carry; bound the argument with `Defined`",
+        note = "in generic code, add `{Self}: NatFactorize` to the `where` clause"
+    )]
+    (Factorize) NatFactorize(Nat): NatList {
+        [(One Term)] => NatNil
+        forall (N: Nat) {
+            [(Zero N)] => (@NatFactorizeInternal (Zero N) (@NatSmallestFactor (Zero N)))
+            [(One (Zero N))] => (@NatFactorizeInternal (One (Zero N)) (@NatSmallestFactor (One (Zero N))))
+            [(One (One N))] => (@NatFactorizeInternal (One (One N)) (@NatSmallestFactor (One (One N))))
+            [(One (Two N))] => (@NatFactorizeInternal (One (Two N)) (@NatSmallestFactor (One (Two N))))
+            [(Two N)] => (@NatFactorizeInternal (Two N) (@NatSmallestFactor (Two N)))
+        }
+    }
+
+    (FactorizeInternal) NatFactorizeInternal(Nat, Nat): NatList {
+        forall (N: Nat, P: Nat) {
+            [N, P] => (NatCons P (@NatFactorize (@NatDiv N P)))
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::marker::PhantomData;
+
+    use super::*;
+
+    #[test]
+    fn ternary_is_even_odd() {
+        assert!(<IsEven<U0> as Bool>::reify());
+        assert!(!<IsEven<U1> as Bool>::reify());
+        assert!(<IsEven<U2> as Bool>::reify());
+        assert!(!<IsEven<U3> as Bool>::reify());
+        assert!(<IsEven<U4> as Bool>::reify());
+        assert!(<IsEven<U12> as Bool>::reify());
+        assert!(!<IsEven<U25> as Bool>::reify());
+        assert!(!<IsOdd<U0> as Bool>::reify());
+        assert!(<IsOdd<U7> as Bool>::reify());
+        assert!(!<IsOdd<U26> as Bool>::reify());
+        assert!(<IsOdd<U81> as Bool>::reify());
+        assert!(<::strong::ternary::IsEven<U4> as Bool>::reify());
+        let _: PhantomData<BoolUndefined> = PhantomData::<IsEven<Sub<U1, U2>>>;
+        let _: PhantomData<BoolUndefined> = PhantomData::<IsOdd<Div<U1, U0>>>;
+    }
+
+    #[test]
+    fn ternary_smallest_factor() {
+        assert_eq!(<SmallestFactor<U2> as Nat>::reify(), 2);
+        assert_eq!(<SmallestFactor<U3> as Nat>::reify(), 3);
+        assert_eq!(<SmallestFactor<U4> as Nat>::reify(), 2);
+        assert_eq!(<SmallestFactor<U9> as Nat>::reify(), 3);
+        assert_eq!(<SmallestFactor<U15> as Nat>::reify(), 3);
+        assert_eq!(<SmallestFactor<U25> as Nat>::reify(), 5);
+        assert_eq!(<SmallestFactor<U29> as Nat>::reify(), 29);
+        assert_eq!(<SmallestFactor<U49> as Nat>::reify(), 7);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ternary_smallest_factor_undefined() {
+        let _: Undefined = <SmallestFactor<U1>>::default();
+        let _ = <SmallestFactor<U0> as Nat>::reify();
+    }
+
+    #[test]
+    fn ternary_is_prime() {
+        assert!(!<IsPrime<U0> as Bool>::reify());
+        assert!(!<IsPrime<U1> as Bool>::reify());
+        assert!(<IsPrime<U2> as Bool>::reify());
+        assert!(<IsPrime<U3> as Bool>::reify());
+        assert!(!<IsPrime<U4> as Bool>::reify());
+        assert!(<IsPrime<U5> as Bool>::reify());
+        assert!(!<IsPrime<U9> as Bool>::reify());
+        assert!(<IsPrime<U13> as Bool>::reify());
+        assert!(!<IsPrime<U25> as Bool>::reify());
+        assert!(<IsPrime<U31> as Bool>::reify());
+        assert!(!<IsPrime<U49> as Bool>::reify());
+        assert!(<IsPrime<U53> as Bool>::reify());
+        let _: PhantomData<BoolUndefined> = PhantomData::<IsPrime<Sub<U1, U2>>>;
+        let _: PhantomData<BoolUndefined> = PhantomData::<IsPrime<Undefined>>;
+    }
+
+    #[test]
+    fn ternary_factorize() {
+        assert_eq!(<Factorize<U1> as NatList>::reify(), vec![]);
+        assert_eq!(<Factorize<U2> as NatList>::reify(), vec![2]);
+        assert_eq!(<Factorize<U12> as NatList>::reify(), vec![2, 2, 3]);
+        assert_eq!(<Factorize<U30> as NatList>::reify(), vec![2, 3, 5]);
+        assert_eq!(<Factorize<U49> as NatList>::reify(), vec![7, 7]);
+        assert_eq!(<Factorize<U53> as NatList>::reify(), vec![53]);
+        assert_eq!(<Factorize<U81> as NatList>::reify(), vec![3, 3, 3, 3]);
+    }
+}
@@ -0,0 +1,208 @@
+//! Type-level three-valued logic. The connectives are those of Kleene's strong logic of
+//! indeterminacy, in which `And` and `Or` are the minimum and maximum of their arguments under
+//! the ordering `TFalse < TUnknown < TTrue`; implication is Łukasiewicz's, under which
+//! "unknown implies unknown" is true.
+
+pub use types::trilean::*;
+
+use strong::boolean::{Bool, False, True, Undefined as BoolUndefined};
+use strong::balanced::{Int, Term as IntTerm, Plus as IntPlus, Minus as IntMinus};
+
+type_operators! {
+    [A, B, C, D, E]
+
+    /// Three-valued `Not`, swapping `TFalse` and `TTrue`. Use as `Not<X>` or
+    /// `<X as TriNot>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Not<{Self}>`",
+        label = "`Not` is not implemented for these arguments",
+        note = "`Not` takes a `Trilean`",
+        note = "in generic code, add `{Self}: TriNot` to the `where` clause"
+    )]
+    (Not) TriNot(Trilean): Trilean {
+        [TFalse] => TTrue
+        [TUnknown] => TUnknown
+        [TTrue] => TFalse
+    }
+
+    /// Three-valued `And`, the lesser of its arguments. Use as `And<X, Y>` or
+    /// `<X as TriAnd<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `And<{Self}, {B}>`",
+        label = "`And` is not implemented for these arguments",
+        note = "`And` takes two `Trilean`s",
+        note = "in generic code, add `{Self}: TriAnd<{B}>` to the `where` clause"
+    )]
+    (And) TriAnd(Trilean, Trilean): Trilean {
+        [TFalse, TFalse] => TFalse
+        [TFalse, TUnknown] => TFalse
+        [TFalse, TTrue] => TFalse
+        [TUnknown, TFalse] => TFalse
+        [TUnknown, TUnknown] => TUnknown
+        [TUnknown, TTrue] => TUnknown
+        [TTrue, TFalse] => TFalse
+        [TTrue, TUnknown] => TUnknown
+        [TTrue, TTrue] => TTrue
+    }
+
+    /// Three-valued `Or`, the greater of its arguments. Use as `Or<X, Y>` or
+    /// `<X as TriOr<Y>>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Or<{Self}, {B}>`",
+        label = "`Or` is not implemented for these arguments",
+        note = "`Or` takes two `Trilean`s",
+        note = "in generic code, add `{Self}: TriOr<{B}>` to the `where` clause"
+    )]
+    (Or) TriOr(Trilean, Trilean): Trilean {
+        [TFalse, TFalse] => TFalse
+        [TFalse, TUnknown] => TUnknown
+        [TFalse, TTrue] => TTrue
+        [TUnknown, TFalse] => TUnknown
+        [TUnknown, TUnknown] => TUnknown
+        [TUnknown, TTrue] => TTrue
+        [TTrue, TFalse] => TTrue
+        [TTrue, TUnknown] => TTrue
+        [TTrue, TTrue] => TTrue
+    }
+
+    /// Łukasiewicz implication. Use as `Implies<X, Y>` or `<X as TriImplies<Y>>::Output`. This
+    /// agrees with Kleene implication, `Or<Not<X>, Y>`, except that `TUnknown` implies itself.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Implies<{Self}, {B}>`",
+        label = "`Implies` is not implemented for these arguments",
+        note = "`Implies` takes two `Trilean`s",
+        note = "in generic code, add `{Self}: TriImplies<{B}>` to the `where` clause"
+    )]
+    (Implies) TriImplies(Trilean, Trilean): Trilean {
+        [TFalse, TFalse] => TTrue
+        [TFalse, TUnknown] => TTrue
+        [TFalse, TTrue] => TTrue
+        [TUnknown, TFalse] => TUnknown
+        [TUnknown, TUnknown] => TTrue
+        [TUnknown, TTrue] => TTrue
+        [TTrue, TFalse] => TFalse
+        [TTrue, TUnknown] => TUnknown
+        [TTrue, TTrue] => TTrue
+    }
+
+    /// Convert a `Bool` to a `Trilean`. Use as `FromBool<B>` or `<B as BoolToTrilean>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `FromBool<{Self}>`",
+        label = "`FromBool` is not implemented for these arguments",
+        note = "`FromBool` takes a `Bool`",
+        note = "in generic code, add `{Self}: BoolToTrilean` to the `where` clause"
+    )]
+    (FromBool) BoolToTrilean(Bool): Trilean {
+        [False] => TFalse
+        [True] => TTrue
+    }
+
+    /// Convert a `Trilean` to a `Bool`. Use as `ToBool<X>` or `<X as TriToBool>::Output`.
+    /// `TUnknown` has no boolean value, and converts to `Undefined`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `ToBool<{Self}>`",
+        label = "`ToBool` is not implemented for these arguments",
+        note = "`ToBool` takes a `Trilean`",
+        note = "in generic code, add `{Self}: TriToBool` to the `where` clause"
+    )]
+    (ToBool) TriToBool(Trilean): Bool {
+        [TFalse] => False
+        [TUnknown] => BoolUndefined
+        [TTrue] => True
+    }
+
+    /// Convert a single balanced trit, given as an `Int` of minus one, zero or one, to a
+    /// `Trilean`. Use as `FromInt<N>` or `<N as IntToTrilean>::Output`. Not implemented for any
+    /// other `Int`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `FromInt<{Self}>`",
+        label = "`FromInt` is not implemented for these arguments",
+        note = "`FromInt` takes an `Int`",
+        note = "only `SN1`, `S0` and `SP1` convert; any other `Int`, `DivByZero` or `Undefined` \
+            has no `Trilean`",
+        note = "nor does a hand-written value with a redundant leading `Zero`, such as \
+            `Plus<Zero<Term>>` for `SP1`; `Normalize` it first",
+        note = "in generic code, add `{Self}: IntToTrilean` to the `where` clause"
+    )]
+    (FromInt) IntToTrilean(Int): Trilean {
+        [(IntMinus IntTerm)] => TFalse
+        [IntTerm] => TUnknown
+        [(IntPlus IntTerm)] => TTrue
+    }
+
+    /// Convert a `Trilean` to the corresponding balanced trit, as an `Int`. Use as `ToInt<X>` or
+    /// `<X as TriToInt>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `ToInt<{Self}>`",
+        label = "`ToInt` is not implemented for these arguments",
+        note = "`ToInt` takes a `Trilean`",
+        note = "in generic code, add `{Self}: TriToInt` to the `where` clause"
+    )]
+    (ToInt) TriToInt(Trilean): Int {
+        [TFalse] => (IntMinus IntTerm)
+        [TUnknown] => IntTerm
+        [TTrue] => (IntPlus IntTerm)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::marker::PhantomData;
+
+    use strong::balanced::{SN1, S0, SP1};
+    use strong::boolean::BoolAnd;
+    use super::*;
+
+    #[test]
+    fn trilean_not() {
+        assert_eq!(<Not<TFalse> as Trilean>::reify(), Some(true));
+        assert_eq!(<Not<TUnknown> as Trilean>::reify(), None);
+        assert_eq!(<Not<TTrue> as Trilean>::reify(), Some(false));
+    }
+
+    #[test]
+    fn trilean_and_or() {
+        assert_eq!(<And<TTrue, TTrue> as Trilean>::reify(), Some(true));
+        assert_eq!(<And<TTrue, TUnknown> as Trilean>::reify(), None);
+        assert_eq!(<And<TUnknown, TFalse> as Trilean>::reify(), Some(false));
+        assert_eq!(<Or<TFalse, TFalse> as Trilean>::reify(), Some(false));
+        assert_eq!(<Or<TFalse, TUnknown> as Trilean>::reify(), None);
+        assert_eq!(<Or<TUnknown, TTrue> as Trilean>::reify(), Some(true));
+        assert_eq!(<Not<And<TUnknown, TTrue>> as Trilean>::reify(),
+                   <Or<Not<TUnknown>, Not<TTrue>> as Trilean>::reify());
+    }
+
+    #[test]
+    fn trilean_implies() {
+        assert_eq!(<Implies<TFalse, TUnknown> as Trilean>::reify(), Some(true));
+        assert_eq!(<Implies<TUnknown, TUnknown> as Trilean>::reify(), Some(true));
+        assert_eq!(<Implies<TUnknown, TFalse> as Trilean>::reify(), None);
+        assert_eq!(<Implies<TTrue, TUnknown> as Trilean>::reify(), None);
+        assert_eq!(<Implies<TTrue, TFalse> as Trilean>::reify(), Some(false));
+        assert_eq!(<Implies<TTrue, TTrue> as Trilean>::reify(), Some(true));
+    }
+
+    #[test]
+    fn trilean_conversions() {
+        assert_eq!(<FromBool<True> as Trilean>::reify(), Some(true));
+        assert_eq!(<FromBool<False> as Trilean>::reify(), Some(false));
+        assert!(<ToBool<TTrue> as Bool>::reify());
+        assert!(!<ToBool<FromBool<False>> as Bool>::reify());
+        assert_eq!(<FromInt<SN1> as Trilean>::reify(), Some(false));
+        assert_eq!(<FromInt<S0> as Trilean>::reify(), None);
+        assert_eq!(<FromInt<SP1> as Trilean>::reify(), Some(true));
+        assert_eq!(<ToInt<TFalse> as Int>::reify(), -1);
+        assert_eq!(<ToInt<Not<TFalse>> as Int>::reify(), 1);
+        let _: SN1 = <ToInt<TFalse>>::default();
+    }
+
+    #[test]
+    #[should_panic(expected = "undefined")]
+    fn trilean_unknown_to_bool() {
+        let _: PhantomData<BoolUndefined> = PhantomData::<ToBool<TUnknown>>;
+        let _: PhantomData<BoolUndefined> =
+            PhantomData::<<True as BoolAnd<ToBool<TUnknown>>>::Output>;
+        let _ = <ToBool<TUnknown> as Bool>::reify();
+    }
+}
@@ -0,0 +1,189 @@
+//! Type-level operations on trit sequences, including conversions between `TritSeq`s and `Nat`s
+//! or `Int`s. A `Nat` or `Int` is converted to a `TritSeq` least significant trit first, which is
+//! the same order that the `Nat` and `Int` representations themselves use; reverse the sequence
+//! with `Rev` to read it most significant trit first.
+
+pub use types::trits::*;
+
+use strong::balanced::{Int, IntError, IntTriple, Term as IntTerm, Zero as IntZero,
+                       Plus as IntPlus, Minus as IntMinus, Undefined as IntUndefined};
+use strong::ternary::{Nat, NatTriple, NatTriplePlusOne, NatTriplePlusTwo, Term as NatTerm,
+                      Zero as NatZero, One as NatOne, Two as NatTwo, Undefined as NatUndefined};
+
+type_operators! {
+    [A, B, C, D, E]
+
+    /// Reverse a `TritSeq`. Used as `Rev<S>` or `<S as TritSeqRev>::Output`.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `Rev<{Self}>`",
+        label = "`Rev` is not implemented for these arguments",
+        note = "`Rev` takes a `TritSeq`",
+        note = "in generic code, add `{Self}: TritSeqRev` to the `where` clause"
+    )]
+    (Rev) TritSeqRev(TritSeq): TritSeq {
+        forall (S: TritSeq) {
+            [S] => (@TritSeqRevInternal S TritNil)
+        }
+    }
+
+    (RevInternal) TritSeqRevInternal(TritSeq, TritSeq): TritSeq {
+        forall (R: TritSeq) {
+            [TritNil, R] => R
+        }
+        forall (H: Trit, T: TritSeq, R: TritSeq) {
+            [(TritCons H T), R] => (# T (TritCons H R))
+        }
+    }
+
+    /// The trits of a `Nat`, least significant first. Used as `FromNat<N>` or
+    /// `<N as NatToTritSeq>::Output`. The sequence for zero is empty.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `FromNat<{Self}>`",
+        label = "`FromNat` is not implemented for these arguments",
+        note = "`FromNat` takes a `Nat`",
+        note = "an error such as `Underflow<L, R>` or `DivByZero` has no trits, so `FromNat` is \
+            not implemented for it; bound the argument with `Defined`",
+        note = "in generic code, add `{Self}: NatToTritSeq` to the `where` clause"
+    )]
+    (FromNat) NatToTritSeq(Nat): TritSeq {
+        [NatTerm] => TritNil
+        forall (N: Nat) {
+            [(NatZero N)] => (TritCons Trit0 (# N))
+            [(NatOne N)] => (TritCons Trit1 (# N))
+            [(NatTwo N)] => (TritCons Trit2 (# N))
+        }
+    }
+
+    /// The `Nat` whose trits, least significant first, are the given `TritSeq`. Used as
+    /// `ToNat<S>` or `<S as TritSeqToNat>::Output`. Leading zeroes are dropped, so the result is
+    /// canonical. Undefined if the sequence contains `TritT`, which is not an unsigned digit.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `ToNat<{Self}>`",
+        label = "`ToNat` is not implemented for these arguments",
+        note = "`ToNat` takes a `TritSeq`",
+        note = "a `TritT` trit has no unsigned value, and makes the result `Undefined`",
+        note = "in generic code, add `{Self}: TritSeqToNat` to the `where` clause"
+    )]
+    (ToNat) TritSeqToNat(TritSeq): Nat {
+        [TritNil] => NatTerm
+        forall (T: TritSeq) {
+            [(TritCons TritT T)] => NatUndefined
+            [(TritCons Trit0 T)] => (@NatTriple (# T))
+            [(TritCons Trit1 T)] => (@NatTriplePlusOne (# T))
+            [(TritCons Trit2 T)] => (@NatTriplePlusTwo (# T))
+        }
+    }
+
+    /// The trits of an `Int`, least significant first. Used as `FromInt<N>` or
+    /// `<N as IntToTritSeq>::Output`. The sequence for zero is empty.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `FromInt<{Self}>`",
+        label = "`FromInt` is not implemented for these arguments",
+        note = "`FromInt` takes an `Int`",
+        note = "`DivByZero` and `Undefined` have no trits, so `FromInt` is not implemented for \
+            them",
+        note = "in generic code, add `{Self}: IntToTritSeq` to the `where` clause"
+    )]
+    (FromInt) IntToTritSeq(Int): TritSeq {
+        [IntTerm] => TritNil
+        forall (N: Int) {
+            [(IntZero N)] => (TritCons Trit0 (# N))
+            [(IntPlus N)] => (TritCons Trit1 (# N))
+            [(IntMinus N)] => (TritCons TritT (# N))
+        }
+    }
+
+    /// The `Int` whose balanced trits, least significant first, are the given `TritSeq`. Used as
+    /// `ToInt<S>` or `<S as TritSeqToInt>::Output`. Leading zeroes are dropped, so the result is
+    /// canonical. Undefined if the sequence contains `Trit2`, which is not a balanced digit.
+    #[diagnostic::on_unimplemented(
+        message = "cannot evaluate `ToInt<{Self}>`",
+        label = "`ToInt` is not implemented for these arguments",
+        note = "`ToInt` takes a `TritSeq`",
+        note = "a `Trit2` trit is not a balanced digit, and makes the result `Undefined`",
+        note = "in generic code, add `{Self}: TritSeqToInt` to the `where` clause"
+    )]
+    (ToInt) TritSeqToInt(TritSeq): Int {
+        [TritNil] => IntTerm
+        forall (T: TritSeq) {
+            [(TritCons TritT T)] => (@IntConsMinus (# T))
+            [(TritCons Trit0 T)] => (@IntTriple (# T))
+            [(TritCons Trit1 T)] => (@IntConsPlus (# T))
+            [(TritCons Trit2 T)] => IntUndefined
+        }
+    }
+
+    /// A convenience operator for propagating undefined values.
+    (ConsPlus) IntConsPlus(Int): Int {
+        [IntTerm] => (IntPlus IntTerm)
+        forall (N: Int) {
+            [(IntZero N)] => (IntPlus (IntZero N))
+            [(IntPlus N)] => (IntPlus (IntPlus N))
+            [(IntMinus N)] => (IntPlus (IntMinus N))
+        }
+        forall (U: IntError) {
+            [U] => U
+        }
+    }
+
+    /// A convenience operator for propagating undefined values.
+    (ConsMinus) IntConsMinus(Int): Int {
+        [IntTerm] => (IntMinus IntTerm)
+        forall (N: Int) {
+            [(IntZero N)] => (IntMinus (IntZero N))
+            [(IntPlus N)] => (IntMinus (IntPlus N))
+            [(IntMinus N)] => (IntMinus (IntMinus N))
+        }
+        forall (U: IntError) {
+            [U] => U
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use strong::balanced::{SN1, SN4, SN9, S0, SP2, SP3, SP13};
+    use strong::ternary::{U0, U1, U3, U5, U9, U17};
+    use super::*;
+
+    #[test]
+    fn trits_rev() {
+        assert_eq!(<Rev<TritNil> as TritSeq>::reify(), vec![]);
+        assert_eq!(<Rev<TritCons<Trit1, TritCons<Trit0, TritCons<Trit2>>>> as TritSeq>::reify(),
+                   vec![2, 0, 1]);
+        assert_eq!(<Rev<TritCons<Trit0, TritCons<Trit0, TritCons<TritT>>>> as TritSeq>::reify(),
+                   vec![-1, 0, 0]);
+    }
+
+    #[test]
+    fn trits_nat() {
+        assert_eq!(<FromNat<U0> as TritSeq>::reify(), vec![]);
+        assert_eq!(<FromNat<U5> as TritSeq>::reify(), vec![2, 1]);
+        assert_eq!(<FromNat<U9> as TritSeq>::reify(), vec![0, 0, 1]);
+        assert_eq!(<ToNat<TritNil> as Nat>::reify(), 0);
+        assert_eq!(<ToNat<FromNat<U17>> as Nat>::reify(), 17);
+        assert_eq!(<ToNat<Rev<FromNat<U3>>> as Nat>::reify(), 1);
+        let _: U1 = <ToNat<Rev<FromNat<U9>>>>::default();
+        let _: U0 = <ToNat<TritCons<Trit0, TritCons<Trit0>>>>::default();
+    }
+
+    #[test]
+    fn trits_int() {
+        assert_eq!(<FromInt<S0> as TritSeq>::reify(), vec![]);
+        assert_eq!(<FromInt<SN4> as TritSeq>::reify(), vec![-1, -1]);
+        assert_eq!(<FromInt<SP13> as TritSeq>::reify(), vec![1, 1, 1]);
+        assert_eq!(<ToInt<FromInt<SN9>> as Int>::reify(), -9);
+        assert_eq!(<ToInt<Rev<FromInt<SP2>>> as Int>::reify(), -2);
+        let _: SN1 = <ToInt<Rev<FromInt<SN9>>>>::default();
+        let _: SP3 = <ToInt<TritCons<Trit0, TritCons<Trit1, TritCons<Trit0>>>>>::default();
+    }
+
+    #[test]
+    #[should_panic]
+    fn trits_undefined() {
+        let _: NatUndefined = <ToNat<TritCons<TritT>>>::default();
+        let _: IntUndefined = <ToInt<TritCons<Trit1, TritCons<Trit2>>>>::default();
+        let _ = <ToInt<TritCons<Trit2>> as Int>::reify();
+    }
+}
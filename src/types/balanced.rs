@@ -44,20 +44,36 @@ type_operators! {
     /// digit at the head of the list. Precautions are taken to avoid issues with non-unique
     /// representations with redundant zeroes such as `Zero<Zero<Term>>` and if one *ever crops up
     /// in your code*, please lodge an issue! It is definitely caused by either a user error or a
-    /// bug.
+    /// bug. Hand-written types can be put into canonical form with the `Normalize` operator,
+    /// tested with `IsCanonical`, and required to be canonical through the `Canonical` marker
+    /// trait.
     ///
     /// `Int` can be reified to `isize`. Like `Nat`s, `Int`s cannot be reified to a constant
     /// expression due to limitations with Rust's associated const fns and constants. If and when
     /// these features are added to Rust in the future they will be added here too.
     ///
+    /// Operations which have no result produce an error value instead, which is then propagated
+    /// through any further operations. `DivByZero` is the result of a division by zero, and any
+    /// other undefined result is `Undefined`. These are both `IntError`s, and reifying either of
+    /// them panics with a message describing the error.
+    ///
     /// `Int`s are always zero-sized, so you can store them directly in your `struct`s instead of
     /// using `PhantomData`. They implement `Default`.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a type-level `Int`",
+        label = "expected an `Int` here",
+        note = "an `Int` is built from the `balanced` constructors `Term`, `Zero`, `Plus` and \
+            `Minus`; a `ternary` constructor such as `One` builds a `Nat`, which is a different \
+            kind",
+        note = "if `{Self}` is a type parameter, add a `{Self}: Int` bound to the `where` clause"
+    )]
     concrete Int: Default => isize where #[derive(Default)] {
         Term => 0,
         Zero(X: Int = Term) => 3 * X,
         Plus(X: Int = Term) => 3 * X + 1,
         Minus(X: Int = Term) => 3 * X - 1,
         Undefined => panic!("Error: This type-level Int value is undefined, and cannot be reified!"),
+        DivByZero => panic!("Error: This type-level Int value is the result of a division by zero, and cannot be reified!"),
         #[cfg(feature = "specialization")]
         Error => panic!("Error: An unexpected, non-Int type has been introduced into type-level arithmetic!"),
         #[cfg(feature = "specialization")]
@@ -66,12 +82,44 @@ type_operators! {
 
     /// The `IntPair` trait and `Int2` struct represent 2-tuples of `Int`s. They are used
     /// internally for defining type-level logic.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not an `IntPair`",
+        label = "expected an `Int2<X, Y>` here",
+        note = "an `IntPair` is an `Int2<X, Y>` of two `Int`s, as returned by `DivRem`"
+    )]
     concrete IntPair: Default => (isize, isize) where #[derive(Default)] {
         Int2(X: Int, Y: Int) => (X, Y),
     }
 }
 
 
+/// The `IntError` trait is implemented by the `Int`s which represent the failure of an operation:
+/// `Undefined` and `DivByZero`. Type operators propagate the first `IntError` among their
+/// arguments.
+///
+/// `IntError` is sealed: the error values above are the only ones.
+#[diagnostic::on_unimplemented(
+    message = "no type operator applies to `{Self}`",
+    label = "`{Self}` is not known to be a valid `Int`",
+    note = "this bound comes from the implementations which pass errors through the `Int` \
+        operators; the operator which really failed is named below",
+    note = "if `{Self}` is a type parameter, add that operator to the `where` clause, as in \
+        `X: IntAdd<Y>`, rather than an `IntError` bound",
+    note = "otherwise, `{Self}` is not an `Int`; see the `Int` trait for how one is built"
+)]
+pub trait IntError: Int + sealed::Sealed {}
+
+impl IntError for Undefined {}
+impl IntError for DivByZero {}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+impl sealed::Sealed for Undefined {}
+impl sealed::Sealed for DivByZero {}
+
+
 pub type SN243 = Zero<Zero<Zero<Zero<Zero<Minus<Term>>>>>>;
 pub type SN81 = Zero<Zero<Zero<Zero<Minus<Term>>>>>;
 pub type SN27 = Zero<Zero<Zero<Minus<Term>>>>;
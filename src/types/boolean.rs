@@ -10,12 +10,37 @@ type_operators! {
     /// logic is through a non-`Bool` type being used. If `specialization` is on, a default
     /// implementation is also generated for *all* types. When `reify` is called on this default
     /// implementation, it panics with an error message explaining that the type is not a `Bool`.
+    ///
+    /// The `Undefined` type is the result of a boolean operation which has no answer, such as
+    /// converting an unknown `Trilean` to a `Bool`. It is propagated by the boolean operators, and
+    /// panics when reified.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a type-level `Bool`",
+        label = "expected `True`, `False` or `Undefined` here",
+        note = "operators such as `IsZero` and `IsPrime` return `Bool`s; `Ordering`s and \
+            `Trilean`s must be converted first",
+        note = "if `{Self}` is a type parameter, add a `{Self}: Bool` bound to the `where` clause"
+    )]
     concrete Bool => bool {
         False => false,
         True => true,
+        Undefined => panic!("Error: This type-level Bool value is undefined, and cannot be reified!"),
         #[cfg(feature = "specialization")]
         Error => panic!("Error: An unexpected, non-Bool type has been introduced into type-level boolean logic!"),
         #[cfg(feature = "specialization")]
         DEFAULT => panic!("Error: This is not a Bool!"),
     }
+
+    /// The `BoolList` trait represents type-level lists of `Bool`s. `BoolNil` is the empty list,
+    /// and `BoolCons<H, T>` is the list with head `H` and tail `T`. `BoolList`s reify to a
+    /// `Vec<bool>`, head first.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a `BoolList`",
+        label = "expected a `BoolNil` or `BoolCons<H, T>` here",
+        note = "every element of a `BoolList` must be a `Bool`"
+    )]
+    concrete BoolList => Vec<bool> {
+        BoolNil => Vec::new(),
+        BoolCons(H: Bool, T: BoolList = BoolNil) => ::std::iter::once(H).chain(T).collect(),
+    }
 }
@@ -0,0 +1,72 @@
+//! Type-level fixed-width ternary words.
+
+use types::boolean::Bool;
+use types::ternary::{Nat, U6, U9};
+use types::balanced::Int;
+
+type_operators! {
+    [A, B, C, D, E]
+
+    /// The `UWord` kind represents an unsigned ternary word with a fixed number of trits, like a
+    /// hardware register. `UFix<W, N>` is the `W`-trit word holding the `Nat` `N`, which should be
+    /// less than `3^W`. Arithmetic on `UWord`s wraps around modulo `3^W`, reporting any carry out
+    /// of the top trit separately; see the `fixed` module of `strong`. `UWord`s reify to the
+    /// `usize` value they hold.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not an unsigned word",
+        label = "expected a `UFix<W, N>` here",
+        note = "wrap a `Nat` in `UFix<W, N>`, or truncate it with `FromNat<N, W>`"
+    )]
+    concrete UWord: Default => usize where #[derive(Default)] {
+        UFix(W: Nat, N: Nat) => N,
+    }
+
+    /// The `IWord` kind represents a balanced ternary word with a fixed number of trits.
+    /// `IFix<W, I>` is the `W`-trit word holding the `Int` `I`, which should lie between
+    /// `-(3^W - 1) / 2` and `(3^W - 1) / 2` inclusive. Arithmetic on `IWord`s wraps around modulo
+    /// `3^W`, reporting any overflow separately. `IWord`s reify to the `isize` value they hold.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a balanced word",
+        label = "expected an `IFix<W, I>` here",
+        note = "wrap an `Int` in `IFix<W, I>`, or truncate it with `FromInt<I, W>`"
+    )]
+    concrete IWord: Default => isize where #[derive(Default)] {
+        IFix(W: Nat, I: Int) => I,
+    }
+
+    /// The result of an arithmetic operation on `UWord`s: the wrapped word, and a `Bool` which
+    /// is `True` if the operation carried out of (or, for subtraction, borrowed into) the top
+    /// trit. Reifies to a `(usize, bool)` pair.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not the result of an unsigned word operation",
+        label = "expected a `UResult<X, C>` here",
+        note = "`UAdd`, `USub` and `UMul` return `UWordResult`s; use `UValue` to get the word"
+    )]
+    concrete UWordResult => (usize, bool) {
+        UResult(X: UWord, C: Bool) => (X, C),
+    }
+
+    /// The result of an arithmetic operation on `IWord`s: the wrapped word, and a `Bool` which is
+    /// `True` if the exact result did not fit in the word. Reifies to an `(isize, bool)` pair.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not the result of a balanced word operation",
+        label = "expected an `IResult<X, O>` here",
+        note = "`IAdd`, `ISub` and `IMul` return `IWordResult`s; use `IValue` to get the word"
+    )]
+    concrete IWordResult => (isize, bool) {
+        IResult(X: IWord, O: Bool) => (X, O),
+    }
+}
+
+
+/// An unsigned six-trit word.
+pub type UTryte<N> = UFix<U6, N>;
+
+/// A balanced six-trit word.
+pub type Tryte<I> = IFix<U6, I>;
+
+/// An unsigned nine-trit word.
+pub type UWord9<N> = UFix<U9, N>;
+
+/// A balanced nine-trit word.
+pub type Word9<I> = IFix<U9, I>;
@@ -0,0 +1,25 @@
+//! Type-level propositional formulas.
+
+use types::ternary::Nat;
+
+type_operators! {
+    [A, B, C, D, E]
+
+    /// The `Formula` kind represents formulas of propositional logic. `Var<N>` is the `N`th
+    /// variable, counting from zero, and `FNot`, `FAnd`, `FOr` and `FImplies` are the usual
+    /// connectives. The variables of a formula are given values by an environment, a `BoolList`
+    /// whose `N`th element is the value of `Var<N>`; see `Eval` in the `formula` module of
+    /// `strong`. `Formula`s reify to a `String` rendering of the formula, such as `(x0 & !x1)`.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a `Formula`",
+        label = "expected a formula built from `Var`, `FNot`, `FAnd`, `FOr` and `FImplies`",
+        note = "a `Bool` is not a formula; variables are numbered by `Nat`s, as in `Var<U0>`"
+    )]
+    concrete Formula => String {
+        Var(N: Nat) => format!("x{}", N),
+        FNot(F: Formula) => format!("!{}", F),
+        FAnd(F: Formula, G: Formula) => format!("({} & {})", F, G),
+        FOr(F: Formula, G: Formula) => format!("({} | {})", F, G),
+        FImplies(F: Formula, G: Formula) => format!("({} -> {})", F, G),
+    }
+}
@@ -4,5 +4,12 @@
 //! "weak" (SFINAE-style) and "strong" (trait bounds *always* required) type operators.
 
 pub mod boolean;
+pub mod ordering;
 pub mod ternary;
 pub mod balanced;
+pub mod trits;
+pub mod fixed;
+pub mod trilean;
+pub mod formula;
+pub mod option;
+pub mod result;
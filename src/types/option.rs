@@ -0,0 +1,21 @@
+//! Type-level optional types.
+
+type_operators! {
+    [A, B, C, D, E]
+
+    /// The `Option` kind represents a type which may be absent, and is the type-level analogue of
+    /// `std::option::Option`: `None` is the absent value, and `Some<T>` holds the type `T`, which
+    /// may be of any kind. Unlike the error values of `Nat` and `Int`, an `Option` can be
+    /// inspected without panicking, which makes it the natural result of a lookup that may fail;
+    /// see the `option` module of `strong`. `Option`s are not reified, since their contents have
+    /// no common kind, but `Unwrap` and `UnwrapOr` recover the contents.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a type-level `Option`",
+        label = "expected `None` or `Some<T>` here",
+        note = "wrap a type in `Some<T>`, or convert a `Nat` or `Int` with `FromNat` or `FromInt`"
+    )]
+    data Option where #[derive(Default)] {
+        None,
+        Some(_),
+    }
+}
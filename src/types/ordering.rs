@@ -0,0 +1,25 @@
+//! Type-level orderings.
+
+type_operators! {
+    [A, B, C, D, E]
+
+    /// The `Ordering` kind represents the result of a comparison, and is the type-level analogue
+    /// of `std::cmp::Ordering`: `Less`, `Equal` and `Greater` reify to the corresponding variants
+    /// of `std::cmp::Ordering`. If the crate is compiled with the `specialization` feature turned
+    /// on, then an `Error` type and a default implementation for all types are also present, just
+    /// as with `Bool`; reifying either of them panics.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a type-level `Ordering`",
+        label = "expected `Less`, `Equal` or `Greater` here",
+        note = "`Ordering`s come from comparisons such as `Compare<X, Y>`"
+    )]
+    concrete Ordering => ::std::cmp::Ordering {
+        Less => ::std::cmp::Ordering::Less,
+        Equal => ::std::cmp::Ordering::Equal,
+        Greater => ::std::cmp::Ordering::Greater,
+        #[cfg(feature = "specialization")]
+        Error => panic!("Error: An unexpected, non-Ordering type has been introduced into type-level comparisons!"),
+        #[cfg(feature = "specialization")]
+        DEFAULT => panic!("Error: This is not an Ordering!"),
+    }
+}
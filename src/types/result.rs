@@ -0,0 +1,22 @@
+//! Type-level results.
+
+type_operators! {
+    [A, B, C, D, E]
+
+    /// The `Result` kind represents the outcome of an operation which may fail, and is the
+    /// type-level analogue of `std::result::Result`: `Ok<T>` holds a successful result `T`, and
+    /// `Err<E>` holds an error `E`. Both may be of any kind. Converting a `Nat` or `Int` with
+    /// `FromNat` or `FromInt` from the `result` module of `strong` gives an `Err` holding its
+    /// error value, such as `Underflow<L, R>` or `DivByZero`, if it is not defined. `Result`s are
+    /// not reified.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a type-level `Result`",
+        label = "expected `Ok<T>` or `Err<E>` here",
+        note = "wrap a type in `Ok<T>` or `Err<E>`, or convert a `Nat` or `Int` with `FromNat` or \
+            `FromInt`"
+    )]
+    data Result where #[derive(Default)] {
+        Ok(_),
+        Err(_),
+    }
+}
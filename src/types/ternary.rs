@@ -23,7 +23,9 @@ type_operators! {
     /// This would be horrible because then, types with equal values (but not equal
     /// representations!) would be considered inequal by the Rust type-checker. If a type with
     /// redundant zeroes surfaces, it is definitely caused by user error or a bug; please lodge an
-    /// issue.
+    /// issue. Hand-written types can be put into canonical form with the `Normalize` operator,
+    /// tested with `IsCanonical`, and required to be canonical through the `Canonical` marker
+    /// trait.
     ///
     /// `Nat`s can be reified to `usize`. Unfortunately, due to the state of associated constants
     /// and const functions in Rust, `Nat`s cannot be reified to a constant expression. As a
@@ -31,15 +33,30 @@ type_operators! {
     /// a sized array paramterized by a `Nat`, the `tll-array` crate is being developed for that
     /// purpose.
     ///
+    /// Operations which have no result produce an error value instead, which is then propagated
+    /// through any further operations. `DivByZero` is the result of a division by zero, and
+    /// `Underflow<L, R>` is the result of subtracting `R` from `L` where `R` is greater - for
+    /// example, `Pred<U0> = Underflow<U0, U1>`. Any other undefined result is `Undefined`. These
+    /// are all `NatError`s, and reifying any of them panics with a message describing the error.
+    ///
     /// `Nat`s are always zero-sized. You can use `PhantomData` to store them in your struct (in
     /// order to avoid Rust's "unused type parameter" error) or you can store them directly - all
     /// `Nat`s implement `Default`.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a type-level `Nat`",
+        label = "expected a `Nat` here",
+        note = "a `Nat` is built from the `ternary` constructors `Term`, `Zero`, `One` and `Two`; \
+            a `balanced` constructor such as `Plus` builds an `Int`, which is a different kind",
+        note = "if `{Self}` is a type parameter, add a `{Self}: Nat` bound to the `where` clause"
+    )]
     concrete Nat: Default => usize where #[derive(Default)] {
         Term => 0,
         Zero(X: Nat = Term) => 3 * X,
         One(X: Nat = Term) => 3 * X + 1,
         Two(X: Nat = Term) => 3 * X + 2,
         Undefined => panic!("Error: This type-level Nat value is undefined, and cannot be reified!"),
+        DivByZero => panic!("Error: This type-level Nat value is the result of a division by zero, and cannot be reified!"),
+        Underflow(L: Nat, R: Nat) => panic!("Error: This type-level Nat value is the result of the underflowing subtraction {} - {}, and cannot be reified!", L, R),
         #[cfg(feature = "specialization")]
         Error => panic!("Error: An unexpected, non-Nat type has been introduced into type-level arithmetic!"),
         #[cfg(feature = "specialization")]
@@ -48,12 +65,59 @@ type_operators! {
 
     /// The `NatPair` trait and `Nat2` struct represent 2-tuples of `Nat`s. They are used
     /// internally for type-level logic.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a `NatPair`",
+        label = "expected a `Nat2<X, Y>` here",
+        note = "a `NatPair` is a `Nat2<X, Y>` of two `Nat`s, as returned by `DivRem`"
+    )]
     concrete NatPair: Default => (usize, usize) where #[derive(Default)] {
         Nat2(X: Nat, Y: Nat) => (X, Y),
     }
+
+    /// The `NatList` trait represents type-level lists of `Nat`s. `NatNil` is the empty list, and
+    /// `NatCons<H, T>` is the list with head `H` and tail `T`. `NatList`s reify to a `Vec<usize>`,
+    /// head first.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a `NatList`",
+        label = "expected a `NatNil` or `NatCons<H, T>` here",
+        note = "every element of a `NatList` must be a `Nat`"
+    )]
+    concrete NatList: Default => Vec<usize> where #[derive(Default)] {
+        NatNil => Vec::new(),
+        NatCons(H: Nat, T: NatList = NatNil) => ::std::iter::once(H).chain(T).collect(),
+    }
 }
 
 
+/// The `NatError` trait is implemented by the `Nat`s which represent the failure of an operation:
+/// `Undefined`, `DivByZero` and `Underflow<L, R>`. Type operators propagate the first
+/// `NatError` among their arguments.
+///
+/// `NatError` is sealed: the error values above are the only ones.
+#[diagnostic::on_unimplemented(
+    message = "no type operator applies to `{Self}`",
+    label = "`{Self}` is not known to be a valid `Nat`",
+    note = "this bound comes from the implementations which pass errors through the `Nat` \
+        operators; the operator which really failed is named below",
+    note = "if `{Self}` is a type parameter, add that operator to the `where` clause, as in \
+        `X: NatAdd<Y>`, rather than a `NatError` bound",
+    note = "otherwise, `{Self}` is not a `Nat`; see the `Nat` trait for how one is built"
+)]
+pub trait NatError: Nat + sealed::Sealed {}
+
+impl NatError for Undefined {}
+impl NatError for DivByZero {}
+impl<L: Nat, R: Nat> NatError for Underflow<L, R> {}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+impl sealed::Sealed for Undefined {}
+impl sealed::Sealed for DivByZero {}
+impl<L: Nat, R: Nat> sealed::Sealed for Underflow<L, R> {}
+
+
 pub type U0 = Term;
 pub type U1 = One<Term>;
 pub type U2 = Two<Term>;
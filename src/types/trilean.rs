@@ -0,0 +1,20 @@
+//! Type-level three-valued logic.
+
+type_operators! {
+    [A, B, C, D, E]
+
+    /// The `Trilean` kind represents the truth values of three-valued logic: `TFalse`, `TUnknown`
+    /// and `TTrue`. These reify to `Some(false)`, `None` and `Some(true)` respectively. They
+    /// correspond to the balanced ternary digits minus one, zero and plus one, and ordering them
+    /// the same way, `TFalse < TUnknown < TTrue`, is what makes the Kleene connectives work.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a `Trilean`",
+        label = "expected `TFalse`, `TUnknown` or `TTrue` here",
+        note = "a `Bool` must be converted first, with `FromBool`"
+    )]
+    concrete Trilean => Option<bool> {
+        TFalse => Some(false),
+        TUnknown => None,
+        TTrue => Some(true),
+    }
+}
@@ -0,0 +1,34 @@
+//! Type-level trits and trit sequences.
+
+type_operators! {
+    [A, B, C, D, E]
+
+    /// The `Trit` kind represents a single ternary digit. `Trit0`, `Trit1` and `Trit2` are the
+    /// digits of unsigned ternary (`Nat`s), while `TritT`, `Trit0` and `Trit1` are the digits of
+    /// balanced ternary (`Int`s), `TritT` standing for minus one. `Trit`s reify to `isize`.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a `Trit`",
+        label = "expected `TritT`, `Trit0`, `Trit1` or `Trit2` here"
+    )]
+    concrete Trit: Default => isize where #[derive(Default)] {
+        TritT => -1,
+        Trit0 => 0,
+        Trit1 => 1,
+        Trit2 => 2,
+    }
+
+    /// The `TritSeq` kind represents an explicit list of `Trit`s. `TritNil` is the empty
+    /// sequence, and `TritCons<H, T>` is the sequence with head `H` and tail `T`. Unlike `Nat`s
+    /// and `Int`s, a `TritSeq` is just a list of digits, and may freely contain leading zeroes;
+    /// this makes it suitable for digit-oriented algorithms which need to read numbers most
+    /// significant trit first. `TritSeq`s reify to a `Vec<isize>`, head first.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a `TritSeq`",
+        label = "expected a `TritNil` or `TritCons<H, T>` here",
+        note = "a `Nat` or `Int` must be converted first, with `FromNat` or `FromInt`"
+    )]
+    concrete TritSeq: Default => Vec<isize> where #[derive(Default)] {
+        TritNil => Vec::new(),
+        TritCons(H: Trit = Trit0, T: TritSeq = TritNil) => ::std::iter::once(H).chain(T).collect(),
+    }
+}